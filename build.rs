@@ -51,16 +51,15 @@ fn generate_bindings(src_dir: path::PathBuf) {
         .collect(),
     );
 
-    #[cfg(feature = "bindgen-source")]
-    let out_dir = &src_dir.join("src");
-    #[cfg(not(feature = "bindgen-source"))]
-    let out_dir =
-        &path::PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR should always be set"));
-
-    let out_file = out_dir.join("bindings.rs");
+    let out_file = bindings_file(&src_dir);
     println!("cargo:rerun-if-changed={}", out_file.display());
 
-    bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default();
+    if let Some(sysroot) = android_sysroot() {
+        builder = builder.clang_arg(format!("--sysroot={}", sysroot.display()));
+    }
+
+    builder
         .rust_target(env!("CARGO_PKG_RUST_VERSION").parse().expect("valid"))
         .disable_header_comment()
         .derive_default(true)
@@ -83,10 +82,12 @@ fn generate_bindings(src_dir: path::PathBuf) {
         .allowlist_type("btf_.+")
         .allowlist_type("xdp_.+")
         .allowlist_type("perf_.+")
+        .allowlist_type("libbpf_errno")
         .allowlist_var("BPF_.+")
         .allowlist_var("BTF_.+")
         .allowlist_var("XDP_.+")
         .allowlist_var("PERF_.+")
+        .allowlist_var("LIBBPF_(MAJOR|MINOR)_VERSION")
         .parse_callbacks(Box::new(ignored_macros))
         .header("bindings.h")
         .clang_arg(format!("-I{}", src_dir.join("libbpf/include").display()))
@@ -103,6 +104,395 @@ fn generate_bindings(src_dir: path::PathBuf) {
 #[cfg(not(feature = "bindgen"))]
 fn generate_bindings(_: path::PathBuf) {}
 
+/// The bindings file that `src/lib.rs` will include. Generated bindings
+/// always go to `OUT_DIR/bindings.rs`; only the checked-in copies in `src/`
+/// have a separate file for Android.
+fn bindings_file(src_dir: &path::Path) -> path::PathBuf {
+    if cfg!(feature = "bindgen") && !cfg!(feature = "bindgen-source") {
+        path::PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR should always be set"))
+            .join("bindings.rs")
+    } else if uses_android_bindings() {
+        src_dir.join("src/bindings_android.rs")
+    } else {
+        src_dir.join("src/bindings.rs")
+    }
+}
+
+/// Generate Rust items derived from the contents of the bindings.
+fn generate_bindings_extras(src_dir: &path::Path) {
+    let bindings = bindings_file(src_dir);
+    println!("cargo:rerun-if-changed={}", bindings.display());
+    let bindings = fs::read_to_string(&bindings).expect("failed to read bindings");
+
+    let out_dir = path::PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("opts.rs"), opts_impls(&bindings)).expect("failed to write opts impls");
+    fs::write(out_dir.join("helper_names.rs"), helper_names(&bindings))
+        .expect("failed to write helper names");
+    fs::write(out_dir.join("anon_types.rs"), anon_type_names(&bindings))
+        .expect("failed to write anonymous type names");
+    if cfg!(feature = "size-t-is-usize") {
+        fs::write(out_dir.join("bindings_usize.rs"), usize_bindings(&bindings))
+            .expect("failed to write usize bindings");
+    }
+}
+
+/// The bindings with `size_t` aliased to `usize` rather than `c_ulong`.
+///
+/// Bindgen always runs with `size_t_is_usize(false)`, so that every bindings
+/// file has exactly one `size_t` alias to rewrite and keeps the name in
+/// signatures.
+fn usize_bindings(bindings: &str) -> String {
+    const ALIAS: &str = "pub type size_t = ::std::os::raw::c_ulong;\n";
+    assert_eq!(
+        bindings.matches(ALIAS).count(),
+        1,
+        "expected a single size_t alias in the bindings"
+    );
+    bindings.replacen(ALIAS, "pub type size_t = usize;\n", 1)
+}
+
+/// Structs whose first field is `sz` but which are not built with
+/// `LIBBPF_OPTS`: bpftool's generated code fills in the skeletons itself.
+const NOT_OPTS: &[&str] = &["bpf_object_skeleton", "bpf_object_subskeleton"];
+
+/// A `LibbpfOpts` impl for every struct in the bindings whose first field is
+/// `sz`, bar [`NOT_OPTS`], so that structs added by libbpf upgrades, such as
+/// `bpf_tc_hook`, are picked up without any manual bookkeeping.
+fn opts_impls(bindings: &str) -> String {
+    let mut impls = String::new();
+    let mut lines = bindings.lines();
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("pub struct ")
+            .and_then(|rest| rest.strip_suffix(" {"))
+        else {
+            continue;
+        };
+        if lines.next() == Some("    pub sz: size_t,") && !NOT_OPTS.contains(&name) {
+            impls.push_str(&format!("impl_libbpf_opts!({name});\n"));
+        }
+    }
+    impls
+}
+
+/// A table mapping every `BPF_FUNC_*` helper id to its C function name, in
+/// ascending id order.
+fn helper_names(bindings: &str) -> String {
+    let mut table = String::from("pub(crate) const HELPER_NAMES: &[(bpf_func_id, &str)] = &[\n");
+    for line in bindings.lines() {
+        let Some(name) = line
+            .strip_prefix("pub const BPF_FUNC_")
+            .and_then(|rest| rest.split_once(": bpf_func_id"))
+            .map(|(name, _)| name)
+        else {
+            continue;
+        };
+        table.push_str(&format!("    (BPF_FUNC_{name}, \"bpf_{name}\"),\n"));
+    }
+    table.push_str("];\n");
+    table
+}
+
+/// The types whose anonymous members get stable names.
+const ANON_TYPE_ROOTS: &[&str] = &["bpf_attr", "perf_event_attr", "bpf_link_info"];
+
+/// Names for anonymous members, which the headers only identify by comments.
+/// Keyed by the stable name of the enclosing type and the member's first
+/// field. Anonymous members not listed here are looked through, so their
+/// named fields are still reachable.
+const ANON_MEMBER_NAMES: &[(&str, &str, &str)] = &[
+    ("bpf_attr", "map_type", "map_create"),
+    ("bpf_attr", "map_fd", "map_elem"),
+    ("bpf_attr", "prog_type", "prog_load"),
+    ("bpf_attr", "pathname", "obj"),
+    ("bpf_attr", "attach_bpf_fd", "prog_attach"),
+    ("bpf_attr", "next_id", "get_id"),
+    ("bpf_attr", "btf", "btf_load"),
+    ("perf_event_attr", "sample_period", "sample"),
+    ("perf_event_attr", "wakeup_events", "wakeup"),
+    ("perf_event_attr", "bp_addr", "config1"),
+    ("perf_event_attr", "bp_len", "config2"),
+    ("perf_event_attr", "aux_action", "aux_action"),
+];
+
+struct BindgenType<'a> {
+    is_union: bool,
+    fields: Vec<(&'a str, &'a str)>,
+}
+
+/// Stable aliases for the anonymous structs and unions nested in
+/// [`ANON_TYPE_ROOTS`], which bindgen names by position, e.g.
+/// `bpf_attr_prog_load` for `bpf_attr__bindgen_ty_4`.
+///
+/// Named fields give `{parent}_{field}`; anonymous members are named from
+/// [`ANON_MEMBER_NAMES`]. Each alias also gets accessors on its nearest
+/// named ancestor, so that `__bindgen_anon_N` fields never have to be
+/// spelled out, and children of a union root get a `From` impl.
+fn anon_type_names(bindings: &str) -> String {
+    let mut types = std::collections::HashMap::new();
+    let mut items = std::collections::HashSet::new();
+    let mut lines = bindings.lines();
+    while let Some(line) = lines.next() {
+        for prefix in [
+            "pub struct ",
+            "pub union ",
+            "pub type ",
+            "pub const ",
+            "pub fn ",
+        ] {
+            if let Some(rest) = line.strip_prefix(prefix) {
+                let end = rest.find([' ', ':', '(', '<', ';']).unwrap_or(rest.len());
+                items.insert(&rest[..end]);
+            }
+        }
+        let (is_union, name) = match (
+            line.strip_prefix("pub struct "),
+            line.strip_prefix("pub union "),
+        ) {
+            (Some(rest), _) => (false, rest),
+            (_, Some(rest)) => (true, rest),
+            _ => continue,
+        };
+        let Some(name) = name.strip_suffix(" {") else {
+            continue;
+        };
+        let fields = lines
+            .by_ref()
+            .take_while(|line| *line != "}")
+            .filter_map(|line| line.trim().strip_prefix("pub ")?.strip_suffix(','))
+            .filter_map(|field| field.split_once(": "))
+            .collect();
+        types.insert(name, BindgenType { is_union, fields });
+    }
+
+    let mut out = String::new();
+    for root in ANON_TYPE_ROOTS {
+        if types.contains_key(root) {
+            anon_type_names_walk(&types, &items, root, root, root, "", false, &mut out);
+        }
+    }
+    out
+}
+
+/// Emits names for the anonymous children of `ty`, which is reached from
+/// the named type `stable` through the field path `path`.
+#[allow(clippy::too_many_arguments)]
+fn anon_type_names_walk(
+    types: &std::collections::HashMap<&str, BindgenType>,
+    items: &std::collections::HashSet<&str>,
+    ty: &str,
+    stable: &str,
+    root: &str,
+    path: &str,
+    through_union: bool,
+    out: &mut String,
+) {
+    let Some(parent) = types.get(ty) else {
+        return;
+    };
+    let through_union = through_union || parent.is_union;
+    for (field, child) in &parent.fields {
+        if !child.starts_with(&format!("{ty}__bindgen_ty_")) {
+            continue;
+        }
+        let child_path = format!("{path}.{field}");
+        let suffix = if field.starts_with("__bindgen_anon_") {
+            let first =
+                types.get(child).and_then(|child| {
+                    child.fields.iter().map(|(name, _)| *name).find(|name| {
+                        !name.starts_with("__bindgen") && !name.starts_with("_bitfield")
+                    })
+                });
+            ANON_MEMBER_NAMES
+                .iter()
+                .find(|(parent, member, _)| *parent == stable && Some(*member) == first)
+                .map(|(_, _, suffix)| *suffix)
+        } else {
+            Some(*field)
+        };
+        let Some(suffix) = suffix else {
+            // Look through unnamed members to their named fields.
+            anon_type_names_walk(
+                types,
+                items,
+                child,
+                stable,
+                root,
+                &child_path,
+                through_union,
+                out,
+            );
+            continue;
+        };
+        let alias = format!("{stable}_{suffix}");
+        if items.contains(alias.as_str()) {
+            println!("cargo:warning=skipping {alias}: name already used by the bindings");
+            continue;
+        }
+        out.push_str(&format!("pub type {alias} = {child};\n"));
+        if child_path.contains("__bindgen_anon_") {
+            let (unsafety, safety_doc, (open, close)) = if through_union {
+                (
+                    "unsafe ",
+                    "    ///\n    /// # Safety\n    ///\n    /// Every byte of the enclosing \
+                     union must be initialized, as it is\n    /// for values built with \
+                     `Default`.\n",
+                    ("unsafe { ", " }"),
+                )
+            } else {
+                ("", "", ("", ""))
+            };
+            out.push_str(&format!("impl {stable} {{\n"));
+            for (name, reference) in [
+                (suffix.to_string(), "&"),
+                (format!("{suffix}_mut"), "&mut "),
+            ] {
+                let self_ = if reference == "&" {
+                    "&self"
+                } else {
+                    "&mut self"
+                };
+                out.push_str(&format!(
+                    "    /// `self{child_path}`\n{safety_doc}    \
+                     pub {unsafety}fn {name}({self_}) -> {reference}{alias} {{\n        \
+                     {open}{reference}self{child_path}{close}\n    }}\n",
+                ));
+            }
+            out.push_str("}\n");
+        }
+        if stable == root && path.is_empty() && parent.is_union {
+            out.push_str(&format!(
+                "impl From<{alias}> for {root} {{\n\
+                 \x20   fn from(value: {alias}) -> Self {{\n\
+                 \x20       let mut attr = Self::default();\n\
+                 \x20       attr{child_path} = value;\n\
+                 \x20       attr\n\
+                 \x20   }}\n\
+                 }}\n"
+            ));
+        }
+        anon_type_names_walk(types, items, child, &alias, root, "", false, out);
+    }
+}
+
+fn is_android() -> bool {
+    env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("android")
+}
+
+/// Whether the checked-in `src/bindings_android.rs` applies. It was generated
+/// for `aarch64-linux-android`; other Android targets share the glibc/musl
+/// bindings, as `src/lib.rs` does.
+fn uses_android_bindings() -> bool {
+    is_android() && env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("aarch64")
+}
+
+/// Locate the NDK sysroot, which bindgen's clang needs to find bionic headers.
+#[cfg(feature = "bindgen")]
+fn android_sysroot() -> Option<path::PathBuf> {
+    if !is_android() {
+        return None;
+    }
+    println!("cargo:rerun-if-env-changed=ANDROID_NDK_ROOT");
+    println!("cargo:rerun-if-env-changed=ANDROID_NDK_HOME");
+    let ndk = env::var_os("ANDROID_NDK_ROOT").or_else(|| env::var_os("ANDROID_NDK_HOME"))?;
+    let prebuilt = path::PathBuf::from(ndk).join("toolchains/llvm/prebuilt");
+    read_dir(prebuilt)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|host| host.path().join("sysroot"))
+        .find(|sysroot| sysroot.is_dir())
+}
+
+/// The `--host` triple passed to autotools `configure` scripts.
+fn autotools_host() -> String {
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    let arch = match arch.as_str() {
+        "riscv64gc" => "riscv64",
+        "riscv32gc" => "riscv32",
+        other => other,
+    };
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    if os == "android" {
+        // The NDK uses vendor-less triples, and 32-bit ARM carries an ABI
+        // suffix in place of the (empty) target_env.
+        return if arch == "arm" {
+            "arm-linux-androideabi".to_string()
+        } else {
+            format!("{arch}-linux-android")
+        };
+    }
+    let vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
+    let env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
+    format!("{arch}-{vendor}-{os}-{env}")
+}
+
+/// Reject feature and target combinations that cannot produce a working
+/// link line, and warn about ones that are merely surprising.
+fn check_features() {
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let crt_static = target_features.split(',').any(|f| f == "crt-static");
+
+    if cfg!(feature = "syscall-only") {
+        let linking = [
+            ("novendor", cfg!(feature = "novendor")),
+            ("vendored-libbpf", cfg!(feature = "vendored-libbpf")),
+            ("vendored-libelf", cfg!(feature = "vendored-libelf")),
+            ("vendored-zlib", cfg!(feature = "vendored-zlib")),
+            ("static-libbpf", cfg!(feature = "static-libbpf")),
+            ("static-libelf", cfg!(feature = "static-libelf")),
+            ("static-zlib", cfg!(feature = "static-zlib")),
+            ("perf-event", cfg!(feature = "perf-event")),
+        ];
+        if let Some((feature, _)) = linking.iter().find(|(_, enabled)| *enabled) {
+            panic!("the `syscall-only` feature of `libbpf-sys` links no native libraries and cannot be combined with `{feature}`; build with `default-features = false`");
+        }
+        return;
+    }
+
+    if cfg!(feature = "fully-static") {
+        if cfg!(feature = "novendor") {
+            panic!(
+                "the `fully-static` and `novendor` features of `libbpf-sys` are mutually exclusive"
+            );
+        }
+        if target_env != "musl" {
+            panic!(
+                "the `fully-static` feature of `libbpf-sys` requires a musl target, not `{}`",
+                env::var("TARGET").unwrap()
+            );
+        }
+        if !crt_static {
+            panic!("the `fully-static` feature of `libbpf-sys` requires a statically linked C runtime; build with RUSTFLAGS=\"-C target-feature=+crt-static\"");
+        }
+    }
+
+    if target_env == "gnu" && (cfg!(feature = "static-libelf") || cfg!(feature = "static-zlib")) {
+        println!("cargo:warning=statically linking libelf or zlib on a glibc target still links glibc dynamically; use the `fully-static` feature of `libbpf-sys` on a musl target for a self-contained binary");
+    }
+
+    if !cfg!(feature = "vendored-libbpf")
+        && (cfg!(feature = "vendored-libelf") || cfg!(feature = "vendored-zlib"))
+    {
+        println!("cargo:warning=the `vendored-libelf` and `vendored-zlib` features of `libbpf-sys` link a system libbpf archive against libraries it was not built with; enable `vendored-libbpf` as well");
+    }
+
+    if is_android() && !cfg!(feature = "vendored-libelf") {
+        println!("cargo:warning=Android does not ship libelf; enable the `vendored-libelf` feature of `libbpf-sys`");
+    }
+}
+
+/// Compile the C helpers the Rust side cannot express portably, such as
+/// `va_copy`.
+fn compile_shims(src_dir: &path::Path) {
+    let shim = src_dir.join("src/vsnprintf.c");
+    println!("cargo:rerun-if-changed={}", shim.display());
+    cc::Build::new()
+        .file(shim)
+        .warnings(true)
+        .compile("libbpf_sys_shims");
+}
+
 fn pkg_check(pkg: &str) {
     if process::Command::new(pkg)
         .stdout(process::Stdio::null())
@@ -121,6 +511,16 @@ fn main() {
     let src_dir = path::PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
 
     generate_bindings(src_dir.clone());
+    generate_bindings_extras(&src_dir);
+    if !cfg!(feature = "syscall-only") {
+        compile_shims(&src_dir);
+    }
+    // Lets `system_include_dirs` find the multiarch headers for the target
+    // the crate was built for.
+    println!(
+        "cargo:rustc-env=LIBBPF_SYS_TARGET={}",
+        env::var("TARGET").unwrap()
+    );
 
     let vendored_libbpf = cfg!(feature = "vendored-libbpf");
     let vendored_libelf = cfg!(feature = "vendored-libelf");
@@ -136,6 +536,17 @@ fn main() {
     println!("Using feature static-libelf={}", static_libelf);
     println!("Using feature static-zlib={}", static_zlib);
 
+    let fully_static = cfg!(feature = "fully-static");
+    println!("Using feature fully-static={}", fully_static);
+
+    check_features();
+
+    if cfg!(feature = "syscall-only") {
+        // Only the bindings' types and constants and the libbpf-free
+        // modules are compiled, so there is nothing to link.
+        return;
+    }
+
     if cfg!(feature = "novendor") {
         println!("cargo:warning=the `novendor` feature of `libbpf-sys` is deprecated; build without features instead");
         println!(
@@ -173,6 +584,9 @@ fn main() {
             cflags.push(" ");
             cflags.push(extra_cflags);
         }
+        if is_android() {
+            cflags.push(android_compat_cflags(&src_dir));
+        }
         (Some(compiler), cflags)
     } else {
         (None, ffi::OsString::new())
@@ -228,17 +642,25 @@ fn main() {
         env::var(global_lib_path),
     ];
 
-    for lib_path in lib_paths {
-        if let Ok(lib_path) = lib_path {
-            for path in lib_path.split(':') {
-                if !path.is_empty() {
-                    println!("cargo:rustc-link-search=native={}", path);
-                }
+    for lib_path in lib_paths.into_iter().flatten() {
+        for path in lib_path.split(':') {
+            if !path.is_empty() {
+                println!("cargo:rustc-link-search=native={}", path);
             }
         }
     }
 }
 
+/// Extra cflags that paper over the parts of glibc which bionic lacks.
+fn android_compat_cflags(src_dir: &path::Path) -> String {
+    let compat_dir = src_dir.join("compat/android");
+    emit_rerun_directives_for_contents(&compat_dir);
+    format!(
+        " -I{dir} -include {dir}/libbpf_sys_compat.h",
+        dir = compat_dir.display()
+    )
+}
+
 fn open_lockable(path: &Path) -> io::Result<File> {
     let result = File::options()
         .read(true)
@@ -323,9 +745,14 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
         })
         .collect();
 
-    #[cfg(target_arch = "aarch64")]
-    cflags.push_str(" -Wno-error=stringop-overflow");
+    // GCC-only warning; clang (e.g. the Android NDK) rejects it under -Werror.
+    if env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("aarch64") && !compiler.is_like_clang() {
+        cflags.push_str(" -Wno-error=stringop-overflow");
+    }
     cflags.push_str(&format!(" -I{}/zlib/", src_dir.display()));
+    if is_android() {
+        cflags.push_str(&android_compat_cflags(src_dir));
+    }
 
     let status = process::Command::new("autoreconf")
         .arg("--install")
@@ -338,7 +765,18 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
 
     // location of libz.a
     let out_lib = format!("-L{}", out_dir.display());
-    let status = process::Command::new("./configure")
+    let mut configure = process::Command::new("./configure");
+    if is_android() {
+        // bionic has no gettext, argp, fts or obstack. Only the elfutils
+        // tools need the latter three, so pre-seed the autoconf cache to
+        // get past the checks without them.
+        configure
+            .arg("--disable-nls")
+            .env("ac_cv_search_argp_parse", "none required")
+            .env("ac_cv_search_fts_close", "none required")
+            .env("ac_cv_search__obstack_free", "none required");
+    }
+    let status = configure
         .arg("--enable-maintainer-mode")
         .arg("--disable-debuginfod")
         .arg("--disable-libdebuginfod")
@@ -347,18 +785,7 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
         .arg("--prefix")
         .arg(src_dir.join("elfutils/prefix_dir"))
         .arg("--host")
-        .arg({
-            let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
-            let arch = match arch.as_str() {
-                "riscv64gc" => "riscv64",
-                "riscv32gc" => "riscv32",
-                other => other,
-            };
-            let vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
-            let env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
-            let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
-            format!("{arch}-{vendor}-{os}-{env}")
-        })
+        .arg(autotools_host())
         .arg("--libdir")
         .arg(out_dir)
         .env("CC", compiler.path())
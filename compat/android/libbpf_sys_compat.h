@@ -0,0 +1,49 @@
+/* Force-included when building the vendored C libraries for Android.
+ *
+ * bionic implements most, but not all, of the GNU extensions that libbpf and
+ * libelf rely on. Fill in the gaps for the API levels that lack them.
+ */
+#ifndef __LIBBPF_SYS_COMPAT_H
+#define __LIBBPF_SYS_COMPAT_H
+
+#ifdef __ANDROID__
+
+#include <errno.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+/* bionic has no secure_getenv(); Android apps never run setuid. */
+#define secure_getenv(name) getenv(name)
+
+#if __ANDROID_API__ < 29
+static inline void *__libbpf_sys_reallocarray(void *ptr, size_t nmemb, size_t size)
+{
+	size_t total;
+
+	if (__builtin_mul_overflow(nmemb, size, &total)) {
+		errno = ENOMEM;
+		return NULL;
+	}
+	return realloc(ptr, total);
+}
+#define reallocarray(ptr, nmemb, size) __libbpf_sys_reallocarray(ptr, nmemb, size)
+#endif /* __ANDROID_API__ < 29 */
+
+static inline void *__libbpf_sys_rawmemchr(const void *s, int c)
+{
+	const unsigned char *p = s;
+
+	while (*p != (unsigned char)c)
+		p++;
+	return (void *)p;
+}
+#define rawmemchr(s, c) __libbpf_sys_rawmemchr(s, c)
+
+#ifndef program_invocation_short_name
+#define program_invocation_short_name getprogname()
+#endif
+
+#endif /* __ANDROID__ */
+
+#endif /* __LIBBPF_SYS_COMPAT_H */
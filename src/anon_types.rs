@@ -0,0 +1,22 @@
+// src/anon_types.rs
+
+//! Stable names for the anonymous structs and unions in `bpf_attr`,
+//! `perf_event_attr` and `bpf_link_info`.
+//!
+//! bindgen names these by position (`bpf_attr__bindgen_ty_4`), so the names
+//! shift whenever the uapi headers gain a member. build.rs derives a
+//! per-command alias for each (`bpf_attr_prog_load`), plus accessors that
+//! hide the positional `__bindgen_anon_N` fields:
+//!
+//! ```
+//! use libbpf_sys::*;
+//!
+//! let mut load = bpf_attr_prog_load::default();
+//! load.prog_type = BPF_PROG_TYPE_SOCKET_FILTER;
+//! let attr = bpf_attr::from(load);
+//! assert_eq!(unsafe { attr.prog_load() }.prog_type, BPF_PROG_TYPE_SOCKET_FILTER);
+//! ```
+
+use crate::*;
+
+include!(concat!(env!("OUT_DIR"), "/anon_types.rs"));
@@ -0,0 +1,654 @@
+// src/asm.rs
+
+//! Assembler for verifier-style BPF assembly text.
+//!
+//! The accepted syntax is the one printed by [`crate::disasm`], the verifier
+//! log and `bpftool prog dump xlated`, so their output can be fed back in
+//! verbatim. On top of that, the assembler understands:
+//!
+//! * labels, written as `name:` on their own line, as jump and call targets;
+//! * helper calls by name, e.g. `call bpf_map_lookup_elem` or
+//!   `call map_lookup_elem`;
+//! * map placeholders, `map[name]` and `map[name][0]+off`, resolved to fds
+//!   passed to [`assemble_with_maps`];
+//! * comments starting with `;`, `//` or `#`; a `#` only starts one at the
+//!   beginning of a line or after whitespace, as it also separates helper
+//!   names from ids in `call name#id`.
+//!
+//! ```
+//! use libbpf_sys::asm::assemble;
+//!
+//! let insns = assemble(
+//!     "
+//!         r0 = 0
+//!         if r1 == 0x0 goto out
+//!         r0 = 1
+//!     out:
+//!         exit
+//!     ",
+//! )
+//! .unwrap();
+//! assert_eq!(insns.len(), 4);
+//! ```
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::disasm::HELPER_NAMES;
+use crate::insn::*;
+use crate::*;
+
+/// An assembly error, with the 1-based source line it occurred on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsmError {
+    pub line: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.msg)
+    }
+}
+
+impl error::Error for AsmError {}
+
+type Result<T> = std::result::Result<T, String>;
+
+/// Assembles `src` into instructions.
+pub fn assemble(src: &str) -> std::result::Result<Vec<bpf_insn>, AsmError> {
+    assemble_with_maps(src, &[])
+}
+
+/// Assembles `src` into instructions, resolving `map[name]` placeholders
+/// to the fds given in `maps`.
+pub fn assemble_with_maps(
+    src: &str,
+    maps: &[(&str, i32)],
+) -> std::result::Result<Vec<bpf_insn>, AsmError> {
+    // First pass: strip comments and prefixes, and assign labels to slots.
+    let mut labels = HashMap::new();
+    let mut stmts = Vec::new();
+    let mut slot = 0;
+    for (idx, line) in src.lines().enumerate() {
+        let line_no = idx + 1;
+        let err = |msg: String| AsmError { line: line_no, msg };
+        let (text, wide) = strip_line(line);
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(label) = text.strip_suffix(':') {
+            if !is_ident(label) {
+                return Err(err(format!("invalid label `{label}`")));
+            }
+            if labels.insert(label, slot).is_some() {
+                return Err(err(format!("duplicate label `{label}`")));
+            }
+            continue;
+        }
+        let wide = wide || is_ld_imm64(text);
+        stmts.push((line_no, slot, text, wide));
+        slot += if wide { 2 } else { 1 };
+    }
+
+    // Second pass: encode.
+    let ctx = Ctx {
+        labels,
+        maps: maps.iter().copied().collect(),
+    };
+    let mut insns = Vec::with_capacity(slot);
+    for (line_no, slot, text, wide) in stmts {
+        let encoded = ctx
+            .encode(text, slot, wide)
+            .map_err(|msg| AsmError { line: line_no, msg })?;
+        insns.extend_from_slice(&encoded);
+    }
+    Ok(insns)
+}
+
+/// Strips comments and any `N: (xx)` prefix, as printed by the disassembler.
+/// Also reports whether the opcode prefix marked the line as `ld_imm64`.
+fn strip_line(line: &str) -> (&str, bool) {
+    let mut text = line;
+    for comment in [";", "//", "#"] {
+        // `#` also separates helper names from ids in `call name#id`.
+        let pos = if comment == "#" {
+            text.match_indices('#').map(|(pos, _)| pos).find(|&pos| {
+                text[..pos]
+                    .chars()
+                    .next_back()
+                    .is_none_or(char::is_whitespace)
+            })
+        } else {
+            text.find(comment)
+        };
+        if let Some(pos) = pos {
+            text = &text[..pos];
+        }
+    }
+    let mut text = text.trim();
+    if let Some((idx, rest)) = text.split_once(':') {
+        if !idx.is_empty() && idx.bytes().all(|b| b.is_ascii_digit()) {
+            text = rest.trim();
+        }
+    }
+    let mut wide = false;
+    if let Some(rest) = text.strip_prefix('(') {
+        if let Some((code, rest)) = rest.split_once(')') {
+            if let Ok(code) = u8::from_str_radix(code, 16) {
+                wide = u32::from(code) == BPF_LD | BPF_IMM | BPF_DW;
+                text = rest.trim();
+            }
+        }
+    }
+    (text, wide)
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '.')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Whether an instruction needs two slots, judging by its text alone.
+///
+/// Only `rN = ...` can be `ld_imm64`: stores of wide immediates are
+/// `BPF_ST` with the value truncated to 32 bits, and `wN` ones are
+/// rejected when encoding.
+fn is_ld_imm64(text: &str) -> bool {
+    let Some((lhs, rhs)) = text.split_once(" = ") else {
+        return false;
+    };
+    if !matches!(parse_reg(lhs), Ok((_, true))) {
+        return false;
+    }
+    let rhs = rhs.trim();
+    rhs.starts_with("map[")
+        || rhs.ends_with(" ll")
+        || parse_imm64(rhs).is_some_and(|imm| i32::try_from(imm as i64).is_err())
+}
+
+fn parse_imm64(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let val = if let Some(hex) = s.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()?
+    } else {
+        s.parse::<u64>().ok()?
+    };
+    Some(if neg { val.wrapping_neg() } else { val })
+}
+
+fn parse_imm(s: &str) -> Result<i32> {
+    let val = parse_imm64(s).ok_or_else(|| format!("invalid immediate `{s}`"))?;
+    // Accept both the signed and the unsigned spelling of 32-bit values.
+    i32::try_from(val as i64)
+        .or_else(|_| u32::try_from(val).map(|v| v as i32))
+        .map_err(|_| format!("immediate `{s}` does not fit in 32 bits"))
+}
+
+fn parse_reg(s: &str) -> Result<(u32, bool)> {
+    let s = s.trim();
+    let (wide, num) = match s.as_bytes().first() {
+        Some(b'r') => (true, &s[1..]),
+        Some(b'w') => (false, &s[1..]),
+        _ => return Err(format!("expected a register, found `{s}`")),
+    };
+    match num.parse::<u32>() {
+        Ok(reg) if reg <= BPF_REG_10 => Ok((reg, wide)),
+        _ => Err(format!("invalid register `{s}`")),
+    }
+}
+
+fn parse_size(s: &str) -> Result<(u32, bool)> {
+    Ok(match s {
+        "u8" => (BPF_B, false),
+        "u16" => (BPF_H, false),
+        "u32" => (BPF_W, false),
+        "u64" => (BPF_DW, false),
+        "s8" => (BPF_B, true),
+        "s16" => (BPF_H, true),
+        "s32" => (BPF_W, true),
+        "s64" => (BPF_DW, true),
+        _ => return Err(format!("invalid access size `{s}`")),
+    })
+}
+
+/// Parses `*(SZ *)(rN +off)` (with `deref`) or `(SZ *)(rN +off)`.
+fn parse_mem(s: &str, deref: bool) -> Result<(u32, bool, u32, i16)> {
+    let s = s.trim();
+    let s = if deref {
+        s.strip_prefix('*')
+            .ok_or_else(|| format!("expected a memory operand, found `{s}`"))?
+    } else {
+        s
+    };
+    let bad = || format!("invalid memory operand `{s}`");
+    let rest = s.strip_prefix('(').ok_or_else(bad)?;
+    let (size, rest) = rest.split_once("*)").ok_or_else(bad)?;
+    let (size, signed) = parse_size(size.trim())?;
+    let inner = rest
+        .trim()
+        .strip_prefix('(')
+        .and_then(|r| r.strip_suffix(')'))
+        .ok_or_else(bad)?;
+    let (reg, off) = match inner.find(['+', '-']) {
+        Some(pos) => (&inner[..pos], inner[pos..].replace(' ', "")),
+        None => (inner, "0".to_string()),
+    };
+    let (reg, _) = parse_reg(reg)?;
+    let off = off
+        .trim_start_matches('+')
+        .parse::<i16>()
+        .map_err(|_| bad())?;
+    Ok((size, signed, reg, off))
+}
+
+fn alu_op(op: &str) -> Option<(u32, i16)> {
+    Some(match op {
+        "+=" => (BPF_ADD, 0),
+        "-=" => (BPF_SUB, 0),
+        "*=" => (BPF_MUL, 0),
+        "/=" => (BPF_DIV, 0),
+        "s/=" => (BPF_DIV, 1),
+        "|=" => (BPF_OR, 0),
+        "&=" => (BPF_AND, 0),
+        "<<=" => (BPF_LSH, 0),
+        ">>=" => (BPF_RSH, 0),
+        "s>>=" => (BPF_ARSH, 0),
+        "%=" => (BPF_MOD, 0),
+        "s%=" => (BPF_MOD, 1),
+        "^=" => (BPF_XOR, 0),
+        "=" => (BPF_MOV, 0),
+        _ => return None,
+    })
+}
+
+fn jmp_op(op: &str) -> Option<u32> {
+    Some(match op {
+        "==" => BPF_JEQ,
+        "!=" => BPF_JNE,
+        ">" => BPF_JGT,
+        ">=" => BPF_JGE,
+        "<" => BPF_JLT,
+        "<=" => BPF_JLE,
+        "s>" => BPF_JSGT,
+        "s>=" => BPF_JSGE,
+        "s<" => BPF_JSLT,
+        "s<=" => BPF_JSLE,
+        "&" => BPF_JSET,
+        _ => return None,
+    })
+}
+
+fn atomic_alu_op(op: &str) -> Option<u32> {
+    Some(match op {
+        "add" => BPF_ADD,
+        "and" => BPF_AND,
+        "or" => BPF_OR,
+        "xor" => BPF_XOR,
+        _ => return None,
+    })
+}
+
+/// Looks up a helper by name, with or without the `bpf_` prefix.
+///
+/// This is the inverse of [`crate::disasm::helper_name`].
+pub fn helper_id(name: &str) -> Option<bpf_func_id> {
+    let name = name.strip_prefix("bpf_").unwrap_or(name);
+    HELPER_NAMES
+        .iter()
+        .find(|(_, helper)| &helper[4..] == name)
+        .map(|&(id, _)| id)
+}
+
+struct Ctx<'a> {
+    labels: HashMap<&'a str, usize>,
+    maps: HashMap<&'a str, i32>,
+}
+
+impl Ctx<'_> {
+    /// Resolves `pc+N`, `pc-N` or a label to an offset relative to `slot`.
+    fn target(&self, s: &str, slot: usize) -> Result<i64> {
+        let s = s.trim();
+        if let Some(rel) = s.strip_prefix("pc") {
+            return rel
+                .trim_start_matches('+')
+                .parse::<i64>()
+                .map_err(|_| format!("invalid jump target `{s}`"));
+        }
+        self.labels
+            .get(s)
+            .map(|&target| target as i64 - slot as i64 - 1)
+            .ok_or_else(|| format!("unknown label `{s}`"))
+    }
+
+    fn target16(&self, s: &str, slot: usize) -> Result<i16> {
+        let off = self.target(s, slot)?;
+        i16::try_from(off).map_err(|_| format!("jump to `{s}` is out of range; use gotol"))
+    }
+
+    fn map_fd(&self, name: &str) -> Result<i32> {
+        if let Some(fd) = name.strip_prefix("fd:") {
+            return fd.parse().map_err(|_| format!("invalid map fd `{fd}`"));
+        }
+        self.maps
+            .get(name)
+            .copied()
+            .ok_or_else(|| format!("unknown map `{name}`"))
+    }
+
+    fn encode(&self, text: &str, slot: usize, wide: bool) -> Result<Vec<bpf_insn>> {
+        let text = text.trim();
+        if text == "exit" {
+            return Ok(vec![BPF_EXIT_INSN()]);
+        }
+        if text == "nospec" {
+            return Ok(vec![BPF_RAW_INSN(BPF_ST | 0xc0, 0, 0, 0, 0)]);
+        }
+        if let Some(target) = text.strip_prefix("goto ") {
+            return Ok(vec![BPF_JMP_A(self.target16(target, slot)?)]);
+        }
+        if let Some(target) = text.strip_prefix("gotol ") {
+            let off = self.target(target, slot)?;
+            let off = i32::try_from(off).map_err(|_| "jump out of range".to_string())?;
+            return Ok(vec![BPF_JMP32_A(off)]);
+        }
+        if let Some(target) = text.strip_prefix("may_goto ") {
+            let off = self.target16(target, slot)?;
+            return Ok(vec![BPF_RAW_INSN(
+                BPF_JMP | BPF_JCOND,
+                0,
+                BPF_MAY_GOTO,
+                off,
+                0,
+            )]);
+        }
+        if let Some(callee) = text.strip_prefix("call ") {
+            return self.encode_call(callee.trim(), slot).map(|insn| vec![insn]);
+        }
+        if let Some(cond) = text.strip_prefix("if ") {
+            return self.encode_jmp(cond, slot).map(|insn| vec![insn]);
+        }
+        if let Some(rest) = text.strip_prefix("lock ") {
+            let (mem, rest) = rest
+                .split_once(')')
+                .and_then(|(a, b)| b.split_once(')').map(|(c, d)| (format!("{a}){c})"), d)))
+                .ok_or_else(|| format!("invalid atomic `{text}`"))?;
+            let (mem_size, _, dst, off) = parse_mem(&mem, true)?;
+            let (op, src) = rest
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| format!("invalid atomic `{text}`"))?;
+            let (op, _) = alu_op(op)
+                .filter(|&(op, _)| matches!(op, BPF_ADD | BPF_AND | BPF_OR | BPF_XOR))
+                .ok_or_else(|| format!("invalid atomic operation `{op}`"))?;
+            let (src, _) = parse_reg(src)?;
+            return Ok(vec![BPF_ATOMIC_OP(mem_size, op, dst, src, off)]);
+        }
+        if let Some(args) = text
+            .strip_prefix("store_release(")
+            .and_then(|a| a.strip_suffix(')'))
+        {
+            let (mem, src) = args
+                .rsplit_once(',')
+                .ok_or_else(|| format!("invalid store_release `{text}`"))?;
+            let (size, _, dst, off) = parse_mem(mem, false)?;
+            let (src, _) = parse_reg(src)?;
+            return Ok(vec![BPF_ATOMIC_OP(size, BPF_STORE_REL, dst, src, off)]);
+        }
+
+        let (lhs, rest) = text
+            .split_once(' ')
+            .ok_or_else(|| format!("cannot parse `{text}`"))?;
+        if lhs.starts_with('*') {
+            // Stores: `*(SZ *)(rD +off) = rS | imm`
+            let (mem, value) = text
+                .split_once(" = ")
+                .ok_or_else(|| format!("invalid store `{text}`"))?;
+            let (size, _, dst, off) = parse_mem(mem, true)?;
+            return Ok(vec![match parse_reg(value) {
+                Ok((src, _)) => BPF_STX_MEM(size, dst, src, off),
+                Err(_) => BPF_ST_MEM(size, dst, off, parse_imm(value)?),
+            }]);
+        }
+
+        let (dst, alu64) = parse_reg(lhs)?;
+        let (op, rhs) = rest
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| format!("cannot parse `{text}`"))?;
+        let rhs = rhs.trim();
+        if op == "=" {
+            if let Some(insns) = self.encode_assign(dst, alu64, rhs, wide)? {
+                return Ok(insns);
+            }
+        }
+        let (op, off) = alu_op(op).ok_or_else(|| format!("unknown operator `{op}`"))?;
+        let (src, movsx) = match rhs.strip_prefix('(') {
+            Some(cast) if op == BPF_MOV => {
+                let (cast, src) = cast
+                    .split_once(')')
+                    .ok_or_else(|| format!("invalid cast in `{text}`"))?;
+                let bits = match cast {
+                    "s8" => 8,
+                    "s16" => 16,
+                    "s32" => 32,
+                    _ => return Err(format!("invalid cast `{cast}`")),
+                };
+                (src, bits)
+            }
+            _ => (rhs, off),
+        };
+        let class = if alu64 { BPF_ALU64 } else { BPF_ALU };
+        Ok(vec![match parse_reg(src) {
+            Ok((src, _)) => BPF_RAW_INSN(class | op | BPF_X, dst, src, movsx, 0),
+            Err(_) => BPF_RAW_INSN(class | op | BPF_K, dst, 0, off, parse_imm(src)?),
+        }])
+    }
+
+    /// Encodes the special forms of `rD = ...`. Returns `None` for plain moves.
+    fn encode_assign(
+        &self,
+        dst: u32,
+        alu64: bool,
+        rhs: &str,
+        wide: bool,
+    ) -> Result<Option<Vec<bpf_insn>>> {
+        // Negation: `rD = -rD`
+        if let Some(reg) = rhs.strip_prefix('-') {
+            if let Ok((src, _)) = parse_reg(reg) {
+                if src != dst {
+                    return Err("negation source and destination must match".to_string());
+                }
+                let class = if alu64 { BPF_ALU64 } else { BPF_ALU };
+                return Ok(Some(vec![BPF_RAW_INSN(class | BPF_NEG, dst, 0, 0, 0)]));
+            }
+        }
+        // Byte swaps: `rD = be16 rD`, `rD = bswap64 rD`
+        for (prefix, ty) in [
+            ("be", Some(BPF_TO_BE)),
+            ("le", Some(BPF_TO_LE)),
+            ("bswap", None),
+        ] {
+            let Some((len, reg)) = rhs.strip_prefix(prefix).and_then(|r| r.split_once(' ')) else {
+                continue;
+            };
+            let (Ok(len), Ok((src, _))) = (len.parse::<i32>(), parse_reg(reg)) else {
+                continue;
+            };
+            if src != dst {
+                return Err("byte swap source and destination must match".to_string());
+            }
+            return Ok(Some(vec![match ty {
+                Some(ty) => BPF_ENDIAN(ty, dst, len),
+                None => BPF_BSWAP(dst, len),
+            }]));
+        }
+        // Loads: `rD = *(SZ *)(rS +off)`, `r0 = *(SZ *)skb[...]`
+        if rhs.starts_with('*') {
+            if let Some((mem, idx)) = rhs.split_once("skb[") {
+                let (size, _) = parse_size(
+                    mem.trim()
+                        .strip_prefix("*(")
+                        .and_then(|m| m.strip_suffix("*)"))
+                        .ok_or_else(|| format!("invalid packet load `{rhs}`"))?
+                        .trim(),
+                )?;
+                let idx = idx
+                    .strip_suffix(']')
+                    .ok_or_else(|| format!("invalid packet load `{rhs}`"))?;
+                return Ok(Some(vec![match idx.split_once('+') {
+                    Some((reg, imm)) => BPF_LD_IND(size, parse_reg(reg)?.0, parse_imm(imm)?),
+                    None => BPF_LD_ABS(size, parse_imm(idx)?),
+                }]));
+            }
+            let (size, signed, src, off) = parse_mem(rhs, true)?;
+            return Ok(Some(vec![if signed {
+                BPF_LDX_MEMSX(size, dst, src, off)
+            } else {
+                BPF_LDX_MEM(size, dst, src, off)
+            }]));
+        }
+        // Map references: `rD = map[name]`, `rD = map[name][0]+off`
+        if let Some(map) = rhs.strip_prefix("map[") {
+            if !alu64 {
+                return Err("map references need a 64-bit register".to_string());
+            }
+            let (name, rest) = map
+                .split_once(']')
+                .ok_or_else(|| format!("invalid map reference `{rhs}`"))?;
+            let fd = self.map_fd(name)?;
+            return Ok(Some(match rest.strip_prefix("[0]+") {
+                Some(off) => BPF_LD_MAP_VALUE(dst, fd, parse_imm(off)?).to_vec(),
+                None if rest.is_empty() => BPF_LD_MAP_FD(dst, fd).to_vec(),
+                None => return Err(format!("invalid map reference `{rhs}`")),
+            }));
+        }
+        // Atomics: `rS = atomic[64]_OP((SZ *)(rD +off), ...)`
+        if let Some(call) = rhs.strip_prefix("atomic") {
+            return self.encode_atomic(dst, call).map(|insn| Some(vec![insn]));
+        }
+        if let Some(args) = rhs
+            .strip_prefix("load_acquire(")
+            .and_then(|a| a.strip_suffix(')'))
+        {
+            let (size, _, src, off) = parse_mem(args, false)?;
+            return Ok(Some(vec![BPF_ATOMIC_OP(size, BPF_LOAD_ACQ, dst, src, off)]));
+        }
+        // Arena pointer casts: `rD = addr_space_cast(rS, DST_AS, SRC_AS)`
+        if let Some(args) = rhs
+            .strip_prefix("addr_space_cast(")
+            .and_then(|a| a.strip_suffix(')'))
+        {
+            let bad = || format!("invalid addr_space_cast `{rhs}`");
+            let mut args = args.split(',');
+            let (Some(src), Some(dst_as), Some(src_as), None) =
+                (args.next(), args.next(), args.next(), args.next())
+            else {
+                return Err(bad());
+            };
+            let (src, _) = parse_reg(src)?;
+            let dst_as = dst_as.trim().parse::<u16>().map_err(|_| bad())?;
+            let src_as = src_as.trim().parse::<u16>().map_err(|_| bad())?;
+            if !alu64 {
+                return Err("addr_space_cast needs a 64-bit register".to_string());
+            }
+            return Ok(Some(vec![BPF_RAW_INSN(
+                BPF_ALU64 | BPF_MOV | BPF_X,
+                dst,
+                src,
+                BPF_ADDR_SPACE_CAST as i16,
+                (u32::from(dst_as) << 16 | u32::from(src_as)) as i32,
+            )]));
+        }
+        // 64-bit immediates: `rD = 0x... ll`
+        if wide {
+            if !alu64 {
+                return Err("ld_imm64 needs a 64-bit register".to_string());
+            }
+            let imm = rhs.strip_suffix(" ll").unwrap_or(rhs);
+            let imm = parse_imm64(imm).ok_or_else(|| format!("invalid immediate `{imm}`"))?;
+            return Ok(Some(BPF_LD_IMM64(dst, imm).to_vec()));
+        }
+        Ok(None)
+    }
+
+    fn encode_atomic(&self, reg: u32, call: &str) -> Result<bpf_insn> {
+        let bad = || format!("invalid atomic `atomic{call}`");
+        let call = call.strip_prefix("64").unwrap_or(call);
+        let (name, args) = call.split_once('(').ok_or_else(bad)?;
+        let args = args.strip_suffix(')').ok_or_else(bad)?;
+        let (mem, rest) = args.split_once("),").ok_or_else(bad)?;
+        let (size, _, dst, off) = parse_mem(&format!("{mem})"), false)?;
+        let src_arg = rest.rsplit(',').next().ok_or_else(bad)?;
+        let (src, _) = parse_reg(src_arg)?;
+        let op = match name {
+            "_xchg" => BPF_XCHG,
+            "_cmpxchg" => {
+                if reg != BPF_REG_0 {
+                    return Err("cmpxchg always writes to r0".to_string());
+                }
+                BPF_CMPXCHG
+            }
+            _ => {
+                let op = name
+                    .strip_prefix("_fetch_")
+                    .and_then(atomic_alu_op)
+                    .ok_or_else(bad)?;
+                op | BPF_FETCH
+            }
+        };
+        if op != BPF_CMPXCHG && src != reg {
+            return Err("atomic fetch result must go to the source register".to_string());
+        }
+        Ok(BPF_ATOMIC_OP(size, op, dst, src, off))
+    }
+
+    fn encode_call(&self, callee: &str, slot: usize) -> Result<bpf_insn> {
+        let name = callee.split_once('#').map_or(callee, |(name, _)| name);
+        if name.starts_with("pc") || self.labels.contains_key(name) {
+            let off = self.target(name, slot)?;
+            let off = i32::try_from(off).map_err(|_| "call out of range".to_string())?;
+            return Ok(BPF_CALL_REL(off));
+        }
+        if name == "kernel-function" {
+            let (_, id) = callee
+                .split_once('#')
+                .ok_or_else(|| "kfunc call needs a BTF id".to_string())?;
+            return Ok(BPF_CALL_KFUNC(0, parse_imm(id)?));
+        }
+        match helper_id(name) {
+            Some(id) => Ok(BPF_EMIT_CALL(id)),
+            None => match callee.split_once('#') {
+                Some((_, id)) => Ok(BPF_EMIT_CALL(parse_imm(id)? as bpf_func_id)),
+                None => Err(format!("unknown helper or label `{name}`")),
+            },
+        }
+    }
+
+    fn encode_jmp(&self, cond: &str, slot: usize) -> Result<bpf_insn> {
+        let bad = || format!("invalid conditional jump `if {cond}`");
+        let (cmp, target) = cond.split_once(" goto ").ok_or_else(bad)?;
+        let mut parts = cmp.split_whitespace();
+        let (lhs, op, rhs) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(lhs), Some(op), Some(rhs), None) => (lhs, op, rhs),
+            _ => return Err(bad()),
+        };
+        let (dst, jmp64) = parse_reg(lhs)?;
+        let op = jmp_op(op).ok_or_else(|| format!("unknown comparison `{op}`"))?;
+        let off = self.target16(target, slot)?;
+        Ok(match (parse_reg(rhs), jmp64) {
+            (Ok((src, _)), true) => BPF_JMP_REG(op, dst, src, off),
+            (Ok((src, _)), false) => BPF_JMP32_REG(op, dst, src, off),
+            (Err(_), true) => BPF_JMP_IMM(op, dst, parse_imm(rhs)?, off),
+            (Err(_), false) => BPF_JMP32_IMM(op, dst, parse_imm(rhs)?, off),
+        })
+    }
+}
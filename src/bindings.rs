@@ -399,6 +399,8 @@ pub const BPF_BUILD_ID_SIZE: u32 = 20;
 pub const BPF_OBJ_NAME_LEN: u32 = 16;
 pub const XDP_PACKET_HEADROOM: u32 = 256;
 pub const BPF_TAG_SIZE: u32 = 8;
+pub const LIBBPF_MAJOR_VERSION: u32 = 1;
+pub const LIBBPF_MINOR_VERSION: u32 = 7;
 pub const BPF_LOG_BUF_SIZE: u32 = 16777215;
 pub const BTF_MAGIC: u32 = 60319;
 pub const BTF_VERSION: u32 = 1;
@@ -8242,6 +8244,23 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn libbpf_version_string() -> *const ::std::os::raw::c_char;
 }
+pub const __LIBBPF_ERRNO__START: libbpf_errno = 4000;
+pub const LIBBPF_ERRNO__LIBELF: libbpf_errno = 4000;
+pub const LIBBPF_ERRNO__FORMAT: libbpf_errno = 4001;
+pub const LIBBPF_ERRNO__KVERSION: libbpf_errno = 4002;
+pub const LIBBPF_ERRNO__ENDIAN: libbpf_errno = 4003;
+pub const LIBBPF_ERRNO__INTERNAL: libbpf_errno = 4004;
+pub const LIBBPF_ERRNO__RELOC: libbpf_errno = 4005;
+pub const LIBBPF_ERRNO__LOAD: libbpf_errno = 4006;
+pub const LIBBPF_ERRNO__VERIFY: libbpf_errno = 4007;
+pub const LIBBPF_ERRNO__PROG2BIG: libbpf_errno = 4008;
+pub const LIBBPF_ERRNO__KVER: libbpf_errno = 4009;
+pub const LIBBPF_ERRNO__PROGTYPE: libbpf_errno = 4010;
+pub const LIBBPF_ERRNO__WRNGPID: libbpf_errno = 4011;
+pub const LIBBPF_ERRNO__INVSEQ: libbpf_errno = 4012;
+pub const LIBBPF_ERRNO__NLPARSE: libbpf_errno = 4013;
+pub const __LIBBPF_ERRNO__END: libbpf_errno = 4014;
+pub type libbpf_errno = ::std::os::raw::c_uint;
 unsafe extern "C" {
     pub fn libbpf_strerror(
         err: ::std::os::raw::c_int,
@@ -0,0 +1,319 @@
+// src/btf_accessors.rs
+
+//! Rust versions of the `static inline` type accessors from `btf.h`.
+//!
+//! bindgen cannot bind inline functions, so BTF consumers would otherwise
+//! have to decode `btf_type::info` and walk the records that follow each
+//! type by hand. The functions here keep the C names and semantics, but
+//! return typed slices in place of pointers.
+//!
+//! Accessors for the variable-length records that follow a `btf_type` are
+//! `unsafe`: they are only sound for a `btf_type` that really is part of a
+//! BTF type section, such as one returned by `btf__type_by_id`.
+//!
+//! The module is not called `btf`, which would shadow the bindings'
+//! `struct btf` in the crate root.
+//!
+//! ```
+//! use libbpf_sys::btf_accessors::{btf_is_ptr, BtfKind};
+//! use libbpf_sys::{btf_type, BTF_KIND_PTR};
+//!
+//! let t = btf_type {
+//!     info: BTF_KIND_PTR << 24,
+//!     ..Default::default()
+//! };
+//! assert!(btf_is_ptr(&t));
+//! assert_eq!(BtfKind::of(&t), Some(BtfKind::Ptr));
+//! ```
+
+use std::slice;
+
+use crate::*;
+
+/// The kind of a BTF type, decoded from `btf_type::info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BtfKind {
+    Unkn,
+    Int,
+    Ptr,
+    Array,
+    Struct,
+    Union,
+    Enum,
+    Fwd,
+    Typedef,
+    Volatile,
+    Const,
+    Restrict,
+    Func,
+    FuncProto,
+    Var,
+    Datasec,
+    Float,
+    DeclTag,
+    TypeTag,
+    Enum64,
+}
+
+impl BtfKind {
+    /// Decodes a raw `BTF_KIND_*` value.
+    pub fn from_raw(kind: u32) -> Option<Self> {
+        Some(match kind {
+            BTF_KIND_UNKN => Self::Unkn,
+            BTF_KIND_INT => Self::Int,
+            BTF_KIND_PTR => Self::Ptr,
+            BTF_KIND_ARRAY => Self::Array,
+            BTF_KIND_STRUCT => Self::Struct,
+            BTF_KIND_UNION => Self::Union,
+            BTF_KIND_ENUM => Self::Enum,
+            BTF_KIND_FWD => Self::Fwd,
+            BTF_KIND_TYPEDEF => Self::Typedef,
+            BTF_KIND_VOLATILE => Self::Volatile,
+            BTF_KIND_CONST => Self::Const,
+            BTF_KIND_RESTRICT => Self::Restrict,
+            BTF_KIND_FUNC => Self::Func,
+            BTF_KIND_FUNC_PROTO => Self::FuncProto,
+            BTF_KIND_VAR => Self::Var,
+            BTF_KIND_DATASEC => Self::Datasec,
+            BTF_KIND_FLOAT => Self::Float,
+            BTF_KIND_DECL_TAG => Self::DeclTag,
+            BTF_KIND_TYPE_TAG => Self::TypeTag,
+            BTF_KIND_ENUM64 => Self::Enum64,
+            _ => return None,
+        })
+    }
+
+    /// Returns the kind of `t`, or `None` if it is newer than these bindings.
+    pub fn of(t: &btf_type) -> Option<Self> {
+        Self::from_raw(btf_kind(t).into())
+    }
+}
+
+/// Returns a pointer to the records that follow `t` in the type section.
+fn trailing<T>(t: &btf_type) -> *const T {
+    (t as *const btf_type).wrapping_add(1).cast()
+}
+
+pub fn btf_kind(t: &btf_type) -> u16 {
+    ((t.info >> 24) & 0x1f) as u16
+}
+
+pub fn btf_vlen(t: &btf_type) -> u16 {
+    (t.info & 0xffff) as u16
+}
+
+pub fn btf_kflag(t: &btf_type) -> bool {
+    (t.info >> 31) != 0
+}
+
+pub fn btf_is_void(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_UNKN
+}
+
+pub fn btf_is_int(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_INT
+}
+
+pub fn btf_is_ptr(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_PTR
+}
+
+pub fn btf_is_array(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_ARRAY
+}
+
+pub fn btf_is_struct(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_STRUCT
+}
+
+pub fn btf_is_union(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_UNION
+}
+
+pub fn btf_is_composite(t: &btf_type) -> bool {
+    btf_is_struct(t) || btf_is_union(t)
+}
+
+pub fn btf_is_enum(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_ENUM
+}
+
+pub fn btf_is_enum64(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_ENUM64
+}
+
+pub fn btf_is_fwd(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_FWD
+}
+
+pub fn btf_is_typedef(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_TYPEDEF
+}
+
+pub fn btf_is_volatile(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_VOLATILE
+}
+
+pub fn btf_is_const(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_CONST
+}
+
+pub fn btf_is_restrict(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_RESTRICT
+}
+
+pub fn btf_is_mod(t: &btf_type) -> bool {
+    matches!(
+        u32::from(btf_kind(t)),
+        BTF_KIND_VOLATILE | BTF_KIND_CONST | BTF_KIND_RESTRICT | BTF_KIND_TYPE_TAG
+    )
+}
+
+pub fn btf_is_func(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_FUNC
+}
+
+pub fn btf_is_func_proto(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_FUNC_PROTO
+}
+
+pub fn btf_is_var(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_VAR
+}
+
+pub fn btf_is_datasec(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_DATASEC
+}
+
+pub fn btf_is_float(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_FLOAT
+}
+
+pub fn btf_is_decl_tag(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_DECL_TAG
+}
+
+pub fn btf_is_type_tag(t: &btf_type) -> bool {
+    u32::from(btf_kind(t)) == BTF_KIND_TYPE_TAG
+}
+
+pub fn btf_is_any_enum(t: &btf_type) -> bool {
+    btf_is_enum(t) || btf_is_enum64(t)
+}
+
+/// The `BTF_INT_*` encoding flags of an `INT` type.
+///
+/// # Safety
+///
+/// `t` must be an `INT` type inside a BTF type section.
+pub unsafe fn btf_int_encoding(t: &btf_type) -> u8 {
+    ((*trailing::<u32>(t) & 0x0f00_0000) >> 24) as u8
+}
+
+/// The bit offset of an `INT` type's value.
+///
+/// # Safety
+///
+/// `t` must be an `INT` type inside a BTF type section.
+pub unsafe fn btf_int_offset(t: &btf_type) -> u8 {
+    ((*trailing::<u32>(t) & 0x00ff_0000) >> 16) as u8
+}
+
+/// The number of value bits of an `INT` type.
+///
+/// # Safety
+///
+/// `t` must be an `INT` type inside a BTF type section.
+pub unsafe fn btf_int_bits(t: &btf_type) -> u8 {
+    (*trailing::<u32>(t) & 0x0000_00ff) as u8
+}
+
+/// # Safety
+///
+/// `t` must be an `ARRAY` type inside a BTF type section.
+pub unsafe fn btf_array(t: &btf_type) -> &btf_array {
+    &*trailing(t)
+}
+
+/// # Safety
+///
+/// `t` must be an `ENUM` type inside a BTF type section.
+pub unsafe fn btf_enum(t: &btf_type) -> &[btf_enum] {
+    slice::from_raw_parts(trailing(t), btf_vlen(t).into())
+}
+
+/// # Safety
+///
+/// `t` must be an `ENUM64` type inside a BTF type section.
+pub unsafe fn btf_enum64(t: &btf_type) -> &[btf_enum64] {
+    slice::from_raw_parts(trailing(t), btf_vlen(t).into())
+}
+
+pub fn btf_enum64_value(e: &btf_enum64) -> u64 {
+    (u64::from(e.val_hi32) << 32) | u64::from(e.val_lo32)
+}
+
+/// # Safety
+///
+/// `t` must be a `STRUCT` or `UNION` type inside a BTF type section.
+pub unsafe fn btf_members(t: &btf_type) -> &[btf_member] {
+    slice::from_raw_parts(trailing(t), btf_vlen(t).into())
+}
+
+/// The bit offset of member `member_idx`, accounting for the bitfield
+/// encoding used when `kflag` is set.
+///
+/// # Safety
+///
+/// `t` must be a `STRUCT` or `UNION` type inside a BTF type section.
+pub unsafe fn btf_member_bit_offset(t: &btf_type, member_idx: u32) -> u32 {
+    let m = &btf_members(t)[member_idx as usize];
+    if btf_kflag(t) {
+        m.offset & 0x00ff_ffff
+    } else {
+        m.offset
+    }
+}
+
+/// The bitfield size of member `member_idx`, or 0 if it is not a bitfield
+/// (or `kflag` is unset).
+///
+/// # Safety
+///
+/// `t` must be a `STRUCT` or `UNION` type inside a BTF type section.
+pub unsafe fn btf_member_bitfield_size(t: &btf_type, member_idx: u32) -> u32 {
+    let m = &btf_members(t)[member_idx as usize];
+    if btf_kflag(t) {
+        m.offset >> 24
+    } else {
+        0
+    }
+}
+
+/// # Safety
+///
+/// `t` must be a `FUNC_PROTO` type inside a BTF type section.
+pub unsafe fn btf_params(t: &btf_type) -> &[btf_param] {
+    slice::from_raw_parts(trailing(t), btf_vlen(t).into())
+}
+
+/// # Safety
+///
+/// `t` must be a `VAR` type inside a BTF type section.
+pub unsafe fn btf_var(t: &btf_type) -> &btf_var {
+    &*trailing(t)
+}
+
+/// # Safety
+///
+/// `t` must be a `DATASEC` type inside a BTF type section.
+pub unsafe fn btf_var_secinfos(t: &btf_type) -> &[btf_var_secinfo] {
+    slice::from_raw_parts(trailing(t), btf_vlen(t).into())
+}
+
+/// # Safety
+///
+/// `t` must be a `DECL_TAG` type inside a BTF type section.
+pub unsafe fn btf_decl_tag(t: &btf_type) -> &btf_decl_tag {
+    &*trailing(t)
+}
@@ -0,0 +1,199 @@
+// src/btf_dump_writer.rs
+
+//! Rust adapter for `btf_dump`.
+//!
+//! `btf_dump__new` emits its output through a printf-style callback taking a
+//! `va_list`. [`BtfDump`] supplies that callback and forwards the formatted
+//! text to any [`std::fmt::Write`] or [`std::io::Write`] implementation.
+
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::os::raw::c_void;
+use std::panic;
+use std::ptr;
+
+use crate::opts::LibbpfOpts;
+use crate::print::format_va_list;
+use crate::print::va_list_arg;
+use crate::*;
+
+type Sink<'a> = dyn FnMut(&str) -> io::Result<()> + 'a;
+
+struct Output<'a> {
+    sink: Box<Sink<'a>>,
+    error: Option<io::Error>,
+}
+
+unsafe extern "C" fn printf_trampoline(ctx: *mut c_void, fmt: *const c_char, args: va_list_arg) {
+    let out = &mut *ctx.cast::<Output<'_>>();
+    if out.error.is_some() {
+        return;
+    }
+    let text = format_va_list(fmt, args);
+    out.error = match panic::catch_unwind(panic::AssertUnwindSafe(|| (out.sink)(&text))) {
+        Ok(result) => result.err(),
+        Err(_) => Some(io::Error::other("btf_dump output callback panicked")),
+    };
+}
+
+/// A `btf_dump` instance whose output goes to a Rust writer.
+pub struct BtfDump<'a> {
+    dump: *mut btf_dump,
+    out: Box<Output<'a>>,
+    _btf: PhantomData<&'a btf>,
+}
+
+impl<'a> BtfDump<'a> {
+    /// Creates a `btf_dump` that writes into a [`fmt::Write`] sink.
+    ///
+    /// # Safety
+    ///
+    /// `btf` must be a valid BTF object that outlives the returned value.
+    pub unsafe fn with_fmt<W: fmt::Write + 'a>(btf: *const btf, mut writer: W) -> io::Result<Self> {
+        Self::new(
+            btf,
+            Box::new(move |s: &str| writer.write_str(s).map_err(io::Error::other)),
+        )
+    }
+
+    /// Creates a `btf_dump` that writes into an [`io::Write`] sink.
+    ///
+    /// # Safety
+    ///
+    /// `btf` must be a valid BTF object that outlives the returned value.
+    pub unsafe fn with_io<W: io::Write + 'a>(btf: *const btf, mut writer: W) -> io::Result<Self> {
+        Self::new(btf, Box::new(move |s: &str| writer.write_all(s.as_bytes())))
+    }
+
+    unsafe fn new(btf: *const btf, sink: Box<Sink<'a>>) -> io::Result<Self> {
+        let mut out = Box::new(Output { sink, error: None });
+        let dump = btf_dump__new(
+            btf,
+            Some(printf_trampoline),
+            ptr::addr_of_mut!(*out).cast(),
+            ptr::null(),
+        );
+        if dump.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            dump,
+            out,
+            _btf: PhantomData,
+        })
+    }
+
+    /// Returns the underlying `btf_dump` pointer.
+    pub fn as_ptr(&self) -> *mut btf_dump {
+        self.dump
+    }
+
+    fn check(&mut self, ret: i32) -> io::Result<i32> {
+        if let Some(err) = self.out.error.take() {
+            return Err(err);
+        }
+        if ret < 0 {
+            return Err(io::Error::from_raw_os_error(-ret));
+        }
+        Ok(ret)
+    }
+
+    /// Writes the C definition of type `id` and every type it depends on
+    /// that has not been emitted yet, like `btf_dump__dump_type`.
+    pub fn dump_type(&mut self, id: u32) -> io::Result<()> {
+        let ret = unsafe { btf_dump__dump_type(self.dump, id) };
+        self.check(ret).map(drop)
+    }
+
+    /// Writes a declaration of type `id`, like `btf_dump__emit_type_decl`.
+    pub fn emit_type_decl(
+        &mut self,
+        id: u32,
+        opts: &btf_dump_emit_type_decl_opts,
+    ) -> io::Result<()> {
+        let ret = unsafe { btf_dump__emit_type_decl(self.dump, id, opts) };
+        self.check(ret).map(drop)
+    }
+
+    /// Writes `data`, interpreted as an instance of type `id`, like
+    /// `btf_dump__dump_type_data`. Returns the number of bytes consumed.
+    pub fn dump_type_data(
+        &mut self,
+        id: u32,
+        data: &[u8],
+        opts: &btf_dump_type_data_opts,
+    ) -> io::Result<usize> {
+        let ret = unsafe {
+            btf_dump__dump_type_data(
+                self.dump,
+                id,
+                data.as_ptr().cast(),
+                data.len() as size_t,
+                opts,
+            )
+        };
+        self.check(ret).map(|n| n as usize)
+    }
+}
+
+impl Drop for BtfDump<'_> {
+    fn drop(&mut self) {
+        unsafe { btf_dump__free(self.dump) };
+    }
+}
+
+/// Renders every type in `btf` as a C header, in the style of
+/// `bpftool btf dump file ... format c`.
+///
+/// `guard` is the include guard macro, e.g. `__VMLINUX_H__`.
+///
+/// # Safety
+///
+/// `btf` must be a valid BTF object.
+pub unsafe fn btf_dump_c_header(btf: *const btf, guard: &str) -> io::Result<String> {
+    let mut header = format!(
+        "#ifndef {guard}\n\
+         #define {guard}\n\
+         \n\
+         #ifndef BPF_NO_PRESERVE_ACCESS_INDEX\n\
+         #pragma clang attribute push (__attribute__((preserve_access_index)), apply_to = record)\n\
+         #endif\n\
+         \n"
+    );
+    {
+        let mut dump = BtfDump::with_fmt(btf, &mut header)?;
+        for id in 1..btf__type_cnt(btf) {
+            dump.dump_type(id)?;
+        }
+    }
+    header.push_str(&format!(
+        "\n\
+         #ifndef BPF_NO_PRESERVE_ACCESS_INDEX\n\
+         #pragma clang attribute pop\n\
+         #endif\n\
+         \n\
+         #endif /* {guard} */\n"
+    ));
+    Ok(header)
+}
+
+/// Renders `data`, interpreted as an instance of BTF type `id`, as a string.
+///
+/// When `opts` is `None`, libbpf's defaults are used.
+///
+/// # Safety
+///
+/// `btf` must be a valid BTF object.
+pub unsafe fn btf_dump_type_data_to_string(
+    btf: *const btf,
+    id: u32,
+    data: &[u8],
+    opts: Option<&btf_dump_type_data_opts>,
+) -> io::Result<String> {
+    let default_opts = btf_dump_type_data_opts::new_sized();
+    let mut out = String::new();
+    BtfDump::with_fmt(btf, &mut out)?.dump_type_data(id, data, opts.unwrap_or(&default_opts))?;
+    Ok(out)
+}
@@ -0,0 +1,298 @@
+// src/disasm.rs
+
+//! Disassembler for `bpf_insn` arrays.
+//!
+//! Output follows the syntax of the kernel's `kernel/bpf/disasm.c`, which
+//! is what the verifier log and `bpftool prog dump xlated` print:
+//!
+//! ```text
+//! 0: (b7) r0 = 0
+//! 1: (18) r1 = map[id:3]
+//! 3: (85) call bpf_map_lookup_elem#1
+//! 4: (95) exit
+//! ```
+
+use std::fmt::Write;
+
+use crate::*;
+
+include!(concat!(env!("OUT_DIR"), "/helper_names.rs"));
+
+/// How the immediate of an `ld_imm64` map reference should be labelled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MapRefs {
+    /// Map ids, as found in instructions read back from the kernel with
+    /// `bpf_prog_get_info_by_fd`.
+    #[default]
+    Id,
+    /// Process-local map fds, as found in instructions before loading or
+    /// returned by `bpf_program__insns`.
+    Fd,
+}
+
+/// Returns the C name of a helper function, e.g. `bpf_map_lookup_elem`.
+pub fn helper_name(func_id: bpf_func_id) -> Option<&'static str> {
+    HELPER_NAMES
+        .binary_search_by_key(&func_id, |&(id, _)| id)
+        .ok()
+        .map(|idx| HELPER_NAMES[idx].1)
+}
+
+const fn op_index(code: u8) -> usize {
+    ((code as u32 & 0xf0) >> 4) as usize
+}
+
+const ALU_STRING: [&str; 16] = [
+    "+=", "-=", "*=", "/=", "|=", "&=", "<<=", ">>=", "neg", "%=", "^=", "=", "s>>=", "endian", "",
+    "",
+];
+const ATOMIC_ALU_STRING: [&str; 16] = [
+    "add", "", "", "", "or", "and", "", "", "", "", "xor", "", "", "", "", "",
+];
+const JMP_STRING: [&str; 16] = [
+    "jmp", "==", ">", ">=", "&", "!=", "s>", "s>=", "call", "exit", "<", "<=", "s<", "s<=",
+    "may_goto", "",
+];
+const LDST_STRING: [&str; 4] = ["u32", "u16", "u8", "u64"];
+const LDSX_STRING: [&str; 4] = ["s32", "s16", "s8", "s64"];
+
+fn size_string(code: u8) -> &'static str {
+    LDST_STRING[((code as u32 & 0x18) >> 3) as usize]
+}
+
+fn ld_imm64_string(insn: &bpf_insn, next: Option<&bpf_insn>, map_refs: MapRefs) -> String {
+    let next_imm = next.map_or(0, |next| next.imm);
+    let imm = (u64::from(next_imm as u32) << 32) | u64::from(insn.imm as u32);
+    let map = match map_refs {
+        MapRefs::Id => "id",
+        MapRefs::Fd => "fd",
+    };
+    match u32::from(insn.src_reg()) {
+        BPF_PSEUDO_MAP_FD => format!("map[{map}:{}]", insn.imm as u32),
+        BPF_PSEUDO_MAP_VALUE => format!("map[{map}:{}][0]+{}", insn.imm as u32, next_imm as u32),
+        BPF_PSEUDO_MAP_IDX => format!("map[idx:{}]", insn.imm as u32),
+        BPF_PSEUDO_MAP_IDX_VALUE => {
+            format!("map[idx:{}]+{}", insn.imm as u32, next_imm as u32)
+        }
+        BPF_PSEUDO_BTF_ID => format!("btf_id[{}]", insn.imm as u32),
+        BPF_PSEUDO_FUNC => format!("subprog[{:+}]", insn.imm),
+        _ => format!("{imm:#x}"),
+    }
+}
+
+/// Formats the instruction at `insns[idx]` without its index prefix.
+///
+/// The second slot of an `ld_imm64` is consumed by the first; formatted on
+/// its own it renders as `(00) unknown`.
+pub fn format_insn(insns: &[bpf_insn], idx: usize, map_refs: MapRefs) -> String {
+    let insn = &insns[idx];
+    let code = insn.code;
+    let class = u32::from(code) & 0x07;
+    let op = u32::from(code) & 0xf0;
+    let mode = u32::from(code) & 0xe0;
+    let size = u32::from(code) & 0x18;
+    let src_x = u32::from(code) & 0x08 == BPF_X;
+    let (dst, src, off, imm) = (insn.dst_reg(), insn.src_reg(), insn.off, insn.imm);
+
+    match class {
+        BPF_ALU | BPF_ALU64 => {
+            let r = if class == BPF_ALU { 'w' } else { 'r' };
+            if op == BPF_END {
+                if class == BPF_ALU64 {
+                    format!("({code:02x}) r{dst} = bswap{imm} r{dst}")
+                } else {
+                    let endian = if src_x { "be" } else { "le" };
+                    format!("({code:02x}) r{dst} = {endian}{imm} r{dst}")
+                }
+            } else if op == BPF_NEG {
+                format!("({code:02x}) {r}{dst} = -{r}{dst}")
+            } else if class == BPF_ALU64
+                && op == BPF_MOV
+                && src_x
+                && off == BPF_ADDR_SPACE_CAST as i16
+            {
+                format!(
+                    "({code:02x}) r{dst} = addr_space_cast(r{src}, {}, {})",
+                    (imm as u32) >> 16,
+                    (imm as u32) & 0xffff
+                )
+            } else {
+                let sdiv_smod = (op == BPF_DIV || op == BPF_MOD) && off == 1;
+                let op_str = match (sdiv_smod, op) {
+                    (true, BPF_DIV) => "s/=",
+                    (true, _) => "s%=",
+                    _ => ALU_STRING[op_index(code)],
+                };
+                if src_x {
+                    let movsx = match (op, off) {
+                        (BPF_MOV, 8) => "(s8)",
+                        (BPF_MOV, 16) => "(s16)",
+                        (BPF_MOV, 32) => "(s32)",
+                        _ => "",
+                    };
+                    format!("({code:02x}) {r}{dst} {op_str} {movsx}{r}{src}")
+                } else {
+                    format!("({code:02x}) {r}{dst} {op_str} {imm}")
+                }
+            }
+        }
+        BPF_STX if mode == BPF_MEM => {
+            let sz = size_string(code);
+            format!("({code:02x}) *({sz} *)(r{dst} {off:+}) = r{src}")
+        }
+        BPF_STX if mode == BPF_ATOMIC => {
+            let sz = size_string(code);
+            let width = if size == BPF_DW { "64" } else { "" };
+            let atomic_op = imm as u32;
+            match atomic_op {
+                BPF_ADD | BPF_AND | BPF_OR | BPF_XOR => format!(
+                    "({code:02x}) lock *({sz} *)(r{dst} {off:+}) {} r{src}",
+                    ALU_STRING[(atomic_op >> 4) as usize]
+                ),
+                _ if (atomic_op & !0xf0) == BPF_FETCH
+                    && matches!(atomic_op & 0xf0, BPF_ADD | BPF_AND | BPF_OR | BPF_XOR) =>
+                {
+                    format!(
+                        "({code:02x}) r{src} = atomic{width}_fetch_{}(({sz} *)(r{dst} {off:+}), r{src})",
+                        ATOMIC_ALU_STRING[((atomic_op & 0xf0) >> 4) as usize]
+                    )
+                }
+                BPF_CMPXCHG => format!(
+                    "({code:02x}) r0 = atomic{width}_cmpxchg(({sz} *)(r{dst} {off:+}), r0, r{src})"
+                ),
+                BPF_XCHG => format!(
+                    "({code:02x}) r{src} = atomic{width}_xchg(({sz} *)(r{dst} {off:+}), r{src})"
+                ),
+                BPF_LOAD_ACQ => {
+                    format!("({code:02x}) r{dst} = load_acquire(({sz} *)(r{src} {off:+}))")
+                }
+                BPF_STORE_REL => {
+                    format!("({code:02x}) store_release(({sz} *)(r{dst} {off:+}), r{src})")
+                }
+                _ => format!("BUG_{code:02x}"),
+            }
+        }
+        BPF_ST if mode == BPF_MEM => {
+            let sz = size_string(code);
+            format!("({code:02x}) *({sz} *)(r{dst} {off:+}) = {imm}")
+        }
+        // BPF_ST | BPF_NOSPEC
+        BPF_ST if code == 0xc2 => format!("({code:02x}) nospec"),
+        BPF_LDX if mode == BPF_MEM || mode == BPF_MEMSX => {
+            let sz = if mode == BPF_MEM {
+                size_string(code)
+            } else {
+                LDSX_STRING[(size >> 3) as usize]
+            };
+            format!("({code:02x}) r{dst} = *({sz} *)(r{src} {off:+})")
+        }
+        BPF_LD if mode == BPF_ABS => {
+            format!("({code:02x}) r0 = *({} *)skb[{imm}]", size_string(code))
+        }
+        BPF_LD if mode == BPF_IND => {
+            format!(
+                "({code:02x}) r0 = *({} *)skb[r{src} + {imm}]",
+                size_string(code)
+            )
+        }
+        BPF_LD if mode == BPF_IMM && size == BPF_DW => format!(
+            "({code:02x}) r{dst} = {}",
+            ld_imm64_string(insn, insns.get(idx + 1), map_refs)
+        ),
+        BPF_JMP | BPF_JMP32 => {
+            let r = if class == BPF_JMP32 { 'w' } else { 'r' };
+            match op {
+                BPF_CALL => match u32::from(src) {
+                    BPF_PSEUDO_CALL => format!("({code:02x}) call pc{imm:+}"),
+                    BPF_PSEUDO_KFUNC_CALL => format!("({code:02x}) call kernel-function#{imm}"),
+                    _ => format!(
+                        "({code:02x}) call {}#{imm}",
+                        helper_name(imm as bpf_func_id).unwrap_or("unknown")
+                    ),
+                },
+                BPF_JA if class == BPF_JMP => format!("({code:02x}) goto pc{off:+}"),
+                BPF_JA => format!("({code:02x}) gotol pc{imm:+}"),
+                BPF_JCOND if u32::from(src) == BPF_MAY_GOTO => {
+                    format!("({code:02x}) may_goto pc{off:+}")
+                }
+                BPF_EXIT => format!("({code:02x}) exit"),
+                _ if src_x => format!(
+                    "({code:02x}) if {r}{dst} {} {r}{src} goto pc{off:+}",
+                    JMP_STRING[op_index(code)]
+                ),
+                _ => format!(
+                    "({code:02x}) if {r}{dst} {} {:#x} goto pc{off:+}",
+                    JMP_STRING[op_index(code)],
+                    imm as u32
+                ),
+            }
+        }
+        _ => format!("({code:02x}) unknown"),
+    }
+}
+
+fn is_ld_imm64(insn: &bpf_insn) -> bool {
+    u32::from(insn.code) == BPF_LD | BPF_IMM | BPF_DW
+}
+
+/// Renders `insns` one instruction per line, each prefixed with its index.
+pub fn disassemble(insns: &[bpf_insn], map_refs: MapRefs) -> String {
+    render(insns, map_refs, |_, _| {})
+}
+
+/// Like [`disassemble`], but precedes instructions with the source line
+/// they were compiled from, as `; <source> @ <file>:<line>`.
+///
+/// # Safety
+///
+/// `prog_linfo` must come from `bpf_prog_linfo__new` for the same program,
+/// and `btf` must be the program's BTF, which holds the line strings.
+#[cfg(not(feature = "syscall-only"))]
+pub unsafe fn disassemble_with_line_info(
+    insns: &[bpf_insn],
+    map_refs: MapRefs,
+    prog_linfo: *const bpf_prog_linfo,
+    btf: *const btf,
+) -> String {
+    use std::ffi::CStr;
+    use std::path::Path;
+
+    let name = |off: u32| {
+        let s = btf__name_by_offset(btf, off);
+        if s.is_null() {
+            ""
+        } else {
+            CStr::from_ptr(s).to_str().unwrap_or("")
+        }
+    };
+    render(insns, map_refs, |out, idx| {
+        let linfo = bpf_prog_linfo__lfind(prog_linfo, idx as u32, 0);
+        let Some(linfo) = linfo.as_ref() else {
+            return;
+        };
+        if linfo.insn_off as usize != idx {
+            return;
+        }
+        let line = name(linfo.line_off);
+        let file = Path::new(name(linfo.file_name_off))
+            .file_name()
+            .and_then(|f| f.to_str())
+            .unwrap_or("");
+        let _ = writeln!(out, "; {line} @ {file}:{}", linfo.line_col >> 10);
+    })
+}
+
+fn render(
+    insns: &[bpf_insn],
+    map_refs: MapRefs,
+    mut before: impl FnMut(&mut String, usize),
+) -> String {
+    let mut out = String::new();
+    let mut idx = 0;
+    while idx < insns.len() {
+        before(&mut out, idx);
+        let _ = writeln!(out, "{idx}: {}", format_insn(insns, idx, map_refs));
+        idx += if is_ld_imm64(&insns[idx]) { 2 } else { 1 };
+    }
+    out
+}
@@ -0,0 +1,128 @@
+// src/error.rs
+
+//! A unified error type for libbpf calls.
+//!
+//! libbpf reports failures as a negative return value, as a NULL pointer
+//! with `errno` set, or, for object-level failures, with one of its own
+//! `LIBBPF_ERRNO__*` codes that only `libbpf_strerror` can describe.
+//! [`LibbpfError`] covers all three and converts into `io::Error`.
+//!
+//! ```no_run
+//! use libbpf_sys::error::LibbpfError;
+//! use libbpf_sys::*;
+//!
+//! let obj = LibbpfError::check_ptr(unsafe {
+//!     bpf_object__open(c"prog.bpf.o".as_ptr())
+//! })?;
+//! let ret = LibbpfError::check(unsafe { bpf_object__load(obj.as_ptr()) });
+//! if let Err(err) = ret {
+//!     if err.libbpf_errno() == Some(LIBBPF_ERRNO__VERIFY) {
+//!         eprintln!("rejected by the verifier: {err}");
+//!     }
+//! }
+//! # Ok::<(), LibbpfError>(())
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::os::raw::c_char;
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+
+use crate::*;
+
+// The `errno` values checked for across the crate, which are the same on
+// every Linux architecture.
+/// Returned for missing keys, ids past the last object and freed objects.
+pub(crate) const ENOENT: c_int = 2;
+/// Used for NULL pointers returned without `errno` set, and reported to
+/// libbpf when a callback panics.
+pub(crate) const EINVAL: c_int = 22;
+/// Returned when a log or batch buffer is too small.
+pub(crate) const ENOSPC: c_int = 28;
+
+/// An error from libbpf: either an `errno` value or a libbpf-specific
+/// `LIBBPF_ERRNO__*` code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LibbpfError {
+    code: c_int,
+}
+
+impl LibbpfError {
+    /// Creates an error from a code, with either sign, as returned by libbpf.
+    pub fn from_code(code: c_int) -> Self {
+        Self {
+            code: code.saturating_abs(),
+        }
+    }
+
+    /// Creates an error from the current `errno`.
+    pub fn last_os_error() -> Self {
+        Self::from_code(io::Error::last_os_error().raw_os_error().unwrap_or(EINVAL))
+    }
+
+    /// Converts an int-returning call's result: negative values are errors,
+    /// anything else is passed through.
+    pub fn check(ret: c_int) -> Result<c_int, Self> {
+        if ret < 0 {
+            Err(Self::from_code(ret))
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// Converts a pointer-returning call's result with the semantics of
+    /// `libbpf_get_error`: a NULL or error-encoded pointer is an error, taken
+    /// from `errno` in the former case. Must be called before anything else
+    /// can change `errno`.
+    pub fn check_ptr<T>(ptr: *mut T) -> Result<NonNull<T>, Self> {
+        let err = unsafe { libbpf_get_error(ptr.cast_const().cast()) };
+        match NonNull::new(ptr) {
+            Some(ptr) if err == 0 => Ok(ptr),
+            _ if err == 0 => Err(Self::from_code(EINVAL)),
+            _ => Err(Self::from_code(err as c_int)),
+        }
+    }
+
+    /// The positive code, either an `errno` value or a `LIBBPF_ERRNO__*`.
+    pub fn code(&self) -> c_int {
+        self.code
+    }
+
+    /// The `errno` value, unless this is a libbpf-specific error.
+    pub fn raw_os_error(&self) -> Option<c_int> {
+        self.libbpf_errno().is_none().then_some(self.code)
+    }
+
+    /// The `LIBBPF_ERRNO__*` code, if this is a libbpf-specific error.
+    pub fn libbpf_errno(&self) -> Option<libbpf_errno> {
+        let code = self.code as libbpf_errno;
+        (__LIBBPF_ERRNO__START..__LIBBPF_ERRNO__END)
+            .contains(&code)
+            .then_some(code)
+    }
+}
+
+impl fmt::Display for LibbpfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0 as c_char; 128];
+        let ret = unsafe { libbpf_strerror(self.code, buf.as_mut_ptr(), buf.len() as size_t) };
+        if ret < 0 {
+            return write!(f, "unknown libbpf error {}", self.code);
+        }
+        let msg = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+        write!(f, "{} (code {})", msg.to_string_lossy(), self.code)
+    }
+}
+
+impl Error for LibbpfError {}
+
+impl From<LibbpfError> for io::Error {
+    fn from(err: LibbpfError) -> Self {
+        match err.raw_os_error() {
+            Some(errno) => io::Error::from_raw_os_error(errno),
+            None => io::Error::other(err),
+        }
+    }
+}
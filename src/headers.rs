@@ -0,0 +1,115 @@
+// src/headers.rs
+
+//! libbpf headers for compiling BPF programs.
+//!
+//! BPF C sources include `<bpf/bpf_helpers.h>` and friends, which in turn
+//! include uapi headers such as `<linux/bpf.h>`. With `vendored-libbpf`,
+//! `write_headers` lays out the vendored copies of both under a directory
+//! to pass to clang with `-I`. Without it, [`system_include_dirs`] finds the
+//! installed ones instead.
+//!
+//! ```no_run
+//! # #[cfg(feature = "vendored-libbpf")]
+//! # fn main() -> std::io::Result<()> {
+//! use std::process::Command;
+//!
+//! let dir = std::env::temp_dir().join("libbpf-headers");
+//! libbpf_sys::headers::write_headers(&dir)?;
+//! Command::new("clang")
+//!     .args(["-g", "-O2", "-target", "bpf", "-c", "prog.bpf.c", "-o", "prog.bpf.o"])
+//!     .arg(format!("-I{}", dir.display()))
+//!     .status()?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "vendored-libbpf"))]
+//! # fn main() {}
+//! ```
+
+#[cfg(feature = "vendored-libbpf")]
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Writes [`API_HEADERS`](crate::API_HEADERS) to `dir/bpf/` and
+/// [`UAPI_HEADERS`](crate::UAPI_HEADERS) to `dir/linux/`, creating the
+/// directories as needed, so that `-I dir` resolves `<bpf/...>` and
+/// `<linux/...>` includes to the vendored copies.
+///
+/// Headers the uapi headers themselves include, such as `<linux/types.h>`
+/// and `<asm/types.h>`, still come from the system.
+#[cfg(feature = "vendored-libbpf")]
+pub fn write_headers(dir: impl AsRef<Path>) -> io::Result<()> {
+    let dir = dir.as_ref();
+    for (subdir, headers) in [
+        ("bpf", &crate::API_HEADERS[..]),
+        ("linux", &crate::UAPI_HEADERS[..]),
+    ] {
+        let subdir = dir.join(subdir);
+        fs::create_dir_all(&subdir)?;
+        for (name, contents) in headers {
+            fs::write(subdir.join(name), contents)?;
+        }
+    }
+    Ok(())
+}
+
+/// Include directories searched for the system's headers, in order.
+const SYSTEM_INCLUDE_DIRS: &[&str] = &["/usr/local/include", "/usr/include"];
+
+/// Locates the system's libbpf and uapi headers, returning the directories
+/// to pass to clang with `-I`: the one containing `bpf/libbpf.h`, the one
+/// containing `linux/bpf.h` and, on glibc targets, the multiarch directory
+/// providing `asm/types.h` if it is present.
+///
+/// The multiarch directory is the one for the target this crate was built
+/// for. Build scripts, where that is the host, should pass `$TARGET` to
+/// [`system_include_dirs_for_target`] instead.
+///
+/// Fails with `NotFound` if either header is missing, typically because
+/// the libbpf or kernel headers development package is not installed.
+pub fn system_include_dirs() -> io::Result<Vec<PathBuf>> {
+    system_include_dirs_for_target(env!("LIBBPF_SYS_TARGET"))
+}
+
+/// Like [`system_include_dirs`], but with the multiarch directory of the
+/// Rust target triple `target`.
+pub fn system_include_dirs_for_target(target: &str) -> io::Result<Vec<PathBuf>> {
+    let find = |header: &str| {
+        SYSTEM_INCLUDE_DIRS
+            .iter()
+            .map(PathBuf::from)
+            .find(|dir| dir.join(header).is_file())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("<{header}> not found")))
+    };
+
+    let mut dirs = vec![find("bpf/libbpf.h")?];
+    let uapi = find("linux/bpf.h")?;
+    if !dirs.contains(&uapi) {
+        dirs.push(uapi);
+    }
+    if let Some(tuple) = multiarch_tuple(target) {
+        let multiarch = Path::new("/usr/include").join(tuple);
+        if multiarch.join("asm/types.h").is_file() {
+            dirs.push(multiarch);
+        }
+    }
+    Ok(dirs)
+}
+
+/// Maps a Rust target triple to the Debian multiarch tuple naming its
+/// `/usr/include` subdirectory, or `None` for targets without one.
+fn multiarch_tuple(target: &str) -> Option<String> {
+    let arch = target.split('-').next()?;
+    let abi = target.rsplit('-').next()?;
+    if !target.contains("-linux-") || !abi.starts_with("gnu") {
+        return None;
+    }
+    let arch = match arch {
+        "i586" | "i686" => "i386",
+        "riscv64gc" => "riscv64",
+        a if a.starts_with("arm") || a.starts_with("thumb") => "arm",
+        a => a,
+    };
+    Some(format!("{arch}-linux-{abi}"))
+}
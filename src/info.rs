@@ -0,0 +1,480 @@
+// src/info.rs
+
+//! Owned `*_info` structs with their variable-length arrays filled in.
+//!
+//! `BPF_OBJ_GET_INFO_BY_FD` only copies out arrays, such as a program's
+//! map ids or a link's tracepoint name, into buffers the caller provides.
+//! Sizing those takes a first call to learn the lengths, then a second
+//! with buffers and record sizes set. [`ProgInfo`], [`MapInfo`],
+//! [`BtfInfo`] and [`LinkInfo`] do both and own the results.
+//!
+//! These use `bpf_obj_get_info_by_fd`, which works with any libbpf 1.x,
+//! rather than the per-object variants added in libbpf 1.2.
+
+use std::fmt;
+use std::io;
+use std::mem;
+use std::os::fd::AsRawFd;
+use std::os::fd::BorrowedFd;
+use std::os::raw::c_char;
+
+use crate::type_names::LinkType;
+use crate::*;
+
+/// The buffer size for strings whose length the kernel does not report.
+const PATH_MAX: u32 = 4096;
+
+/// Issues `BPF_OBJ_GET_INFO_BY_FD` for `fd` into `info`.
+fn get_info<T>(fd: BorrowedFd<'_>, info: &mut T) -> io::Result<()> {
+    let mut len = mem::size_of::<T>() as u32;
+    let ret = unsafe { bpf_obj_get_info_by_fd(fd.as_raw_fd(), (info as *mut T).cast(), &mut len) };
+    if ret < 0 {
+        Err(io::Error::from_raw_os_error(-ret))
+    } else {
+        Ok(())
+    }
+}
+
+/// A zeroed buffer of `len` elements and its address, as the kernel takes it.
+fn buffer<T: Default + Clone>(len: u32) -> (Vec<T>, u64) {
+    let mut buf = vec![T::default(); len as usize];
+    let ptr = buf.as_mut_ptr() as u64;
+    (buf, ptr)
+}
+
+/// Truncates `buf` to the `len` elements the kernel filled in.
+fn filled<T>(mut buf: Vec<T>, len: u32) -> Vec<T> {
+    buf.truncate(len as usize);
+    buf
+}
+
+/// Like [`filled`], but empties `buf` if the kernel zeroed its pointer
+/// `ptr` to withhold the array rather than failing the call.
+fn filled_unless_withheld<T>(buf: Vec<T>, ptr: u64, len: u32) -> Vec<T> {
+    if ptr == 0 {
+        Vec::new()
+    } else {
+        filled(buf, len)
+    }
+}
+
+/// The string in `buf`, up to its first NUL.
+fn string(buf: &[u8]) -> String {
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+/// The string in a fixed-size `name` field.
+fn name(name: &[c_char]) -> String {
+    // SAFETY: `c_char` and `u8` have the same layout.
+    string(unsafe { &*(name as *const [c_char] as *const [u8]) })
+}
+
+/// A program's `bpf_prog_info` and the arrays it describes.
+///
+/// Arrays the caller may not see come back empty. The kernel withholds
+/// these by zeroing their pointers, e.g. JITed code and kernel symbol
+/// addresses when `kptr_restrict` hides them from a `CAP_BPF` caller.
+#[derive(Debug, Clone)]
+pub struct ProgInfo {
+    /// The fixed-size fields; the array pointers are cleared.
+    pub info: bpf_prog_info,
+    pub map_ids: Vec<u32>,
+    pub jited_prog_insns: Vec<u8>,
+    pub xlated_prog_insns: Vec<bpf_insn>,
+    pub jited_ksyms: Vec<u64>,
+    pub jited_func_lens: Vec<u32>,
+    pub func_info: Vec<bpf_func_info>,
+    pub line_info: Vec<bpf_line_info>,
+    pub jited_line_info: Vec<u64>,
+    pub prog_tags: Vec<[u8; BPF_TAG_SIZE as usize]>,
+}
+
+impl ProgInfo {
+    /// Retrieves the info of the program `fd`.
+    pub fn from_fd(fd: BorrowedFd<'_>) -> io::Result<Self> {
+        let mut lens = bpf_prog_info::default();
+        get_info(fd, &mut lens)?;
+
+        let insn_size = mem::size_of::<bpf_insn>() as u32;
+        let mut info = bpf_prog_info::default();
+        let (map_ids, ptr) = buffer(lens.nr_map_ids);
+        (info.nr_map_ids, info.map_ids) = (lens.nr_map_ids, ptr);
+        let (jited_prog_insns, ptr) = buffer(lens.jited_prog_len);
+        (info.jited_prog_len, info.jited_prog_insns) = (lens.jited_prog_len, ptr);
+        let (xlated_prog_insns, ptr) = buffer(lens.xlated_prog_len / insn_size);
+        (info.xlated_prog_len, info.xlated_prog_insns) = (lens.xlated_prog_len, ptr);
+        let (jited_ksyms, ptr) = buffer(lens.nr_jited_ksyms);
+        (info.nr_jited_ksyms, info.jited_ksyms) = (lens.nr_jited_ksyms, ptr);
+        let (jited_func_lens, ptr) = buffer(lens.nr_jited_func_lens);
+        (info.nr_jited_func_lens, info.jited_func_lens) = (lens.nr_jited_func_lens, ptr);
+        let (func_info, ptr) = buffer(lens.nr_func_info);
+        (info.nr_func_info, info.func_info) = (lens.nr_func_info, ptr);
+        info.func_info_rec_size = mem::size_of::<bpf_func_info>() as u32;
+        let (line_info, ptr) = buffer(lens.nr_line_info);
+        (info.nr_line_info, info.line_info) = (lens.nr_line_info, ptr);
+        info.line_info_rec_size = mem::size_of::<bpf_line_info>() as u32;
+        let (jited_line_info, ptr) = buffer(lens.nr_jited_line_info);
+        (info.nr_jited_line_info, info.jited_line_info) = (lens.nr_jited_line_info, ptr);
+        info.jited_line_info_rec_size = mem::size_of::<u64>() as u32;
+        let (prog_tags, ptr) = buffer(lens.nr_prog_tags);
+        (info.nr_prog_tags, info.prog_tags) = (lens.nr_prog_tags, ptr);
+        get_info(fd, &mut info)?;
+
+        // Arrays the caller may not see have their pointers zeroed. Those
+        // left may have shrunk between the two calls, and the kernel reports
+        // the new lengths; they cannot have grown past the buffers.
+        let result = Self {
+            map_ids: filled_unless_withheld(
+                map_ids,
+                info.map_ids,
+                lens.nr_map_ids.min(info.nr_map_ids),
+            ),
+            jited_prog_insns: filled_unless_withheld(
+                jited_prog_insns,
+                info.jited_prog_insns,
+                lens.jited_prog_len.min(info.jited_prog_len),
+            ),
+            xlated_prog_insns: filled_unless_withheld(
+                xlated_prog_insns,
+                info.xlated_prog_insns,
+                lens.xlated_prog_len.min(info.xlated_prog_len) / insn_size,
+            ),
+            jited_ksyms: filled_unless_withheld(
+                jited_ksyms,
+                info.jited_ksyms,
+                lens.nr_jited_ksyms.min(info.nr_jited_ksyms),
+            ),
+            jited_func_lens: filled_unless_withheld(
+                jited_func_lens,
+                info.jited_func_lens,
+                lens.nr_jited_func_lens.min(info.nr_jited_func_lens),
+            ),
+            func_info: filled_unless_withheld(
+                func_info,
+                info.func_info,
+                lens.nr_func_info.min(info.nr_func_info),
+            ),
+            line_info: filled_unless_withheld(
+                line_info,
+                info.line_info,
+                lens.nr_line_info.min(info.nr_line_info),
+            ),
+            jited_line_info: filled_unless_withheld(
+                jited_line_info,
+                info.jited_line_info,
+                lens.nr_jited_line_info.min(info.nr_jited_line_info),
+            ),
+            prog_tags: filled_unless_withheld(
+                prog_tags,
+                info.prog_tags,
+                lens.nr_prog_tags.min(info.nr_prog_tags),
+            ),
+            info: bpf_prog_info {
+                map_ids: 0,
+                jited_prog_insns: 0,
+                xlated_prog_insns: 0,
+                jited_ksyms: 0,
+                jited_func_lens: 0,
+                func_info: 0,
+                line_info: 0,
+                jited_line_info: 0,
+                prog_tags: 0,
+                ..info
+            },
+        };
+        Ok(result)
+    }
+
+    /// The program's name.
+    pub fn name(&self) -> String {
+        name(&self.info.name)
+    }
+}
+
+/// A map's `bpf_map_info`.
+#[derive(Debug, Clone)]
+pub struct MapInfo {
+    pub info: bpf_map_info,
+}
+
+impl MapInfo {
+    /// Retrieves the info of the map `fd`.
+    pub fn from_fd(fd: BorrowedFd<'_>) -> io::Result<Self> {
+        let mut info = bpf_map_info::default();
+        get_info(fd, &mut info)?;
+        Ok(Self { info })
+    }
+
+    /// The map's name.
+    pub fn name(&self) -> String {
+        name(&self.info.name)
+    }
+}
+
+/// A BTF object's `bpf_btf_info`, its raw data and its name.
+#[derive(Debug, Clone)]
+pub struct BtfInfo {
+    /// The fixed-size fields; the data and name pointers are cleared.
+    pub info: bpf_btf_info,
+    /// The raw BTF, as accepted by `btf__new`.
+    pub data: Vec<u8>,
+    /// The name of kernel BTF, such as `vmlinux` or a module name; empty for
+    /// BTF loaded by programs.
+    pub name: String,
+}
+
+impl BtfInfo {
+    /// Retrieves the info of the BTF object `fd`.
+    pub fn from_fd(fd: BorrowedFd<'_>) -> io::Result<Self> {
+        let mut lens = bpf_btf_info::default();
+        get_info(fd, &mut lens)?;
+
+        let mut info = bpf_btf_info::default();
+        let (data, ptr) = buffer(lens.btf_size);
+        (info.btf_size, info.btf) = (lens.btf_size, ptr);
+        let (name, ptr) = buffer::<u8>(lens.name_len + 1);
+        (info.name_len, info.name) = (lens.name_len + 1, ptr);
+        get_info(fd, &mut info)?;
+
+        Ok(Self {
+            data: filled(data, lens.btf_size.min(info.btf_size)),
+            name: string(&name),
+            info: bpf_btf_info {
+                btf: 0,
+                name: 0,
+                ..info
+            },
+        })
+    }
+}
+
+/// The type-specific part of a [`LinkInfo`].
+///
+/// Pointer fields in the wrapped structs are left as the kernel returned
+/// them; the data they pointed to is in the variant's owned fields.
+#[derive(Clone)]
+pub enum LinkDetails {
+    RawTracepoint {
+        info: bpf_link_info_raw_tracepoint,
+        tp_name: String,
+    },
+    Tracing(bpf_link_info_tracing),
+    Cgroup(bpf_link_info_cgroup),
+    Iter {
+        info: bpf_link_info_iter,
+        target_name: String,
+    },
+    Netns(bpf_link_info_netns),
+    Xdp(bpf_link_info_xdp),
+    /// `name` is the uprobe's binary, the kprobe's function or the
+    /// tracepoint, depending on `info.type_`, and empty for other events.
+    PerfEvent {
+        info: bpf_link_info_perf_event,
+        name: String,
+    },
+    KprobeMulti {
+        info: bpf_link_info_kprobe_multi,
+        addrs: Vec<u64>,
+        cookies: Vec<u64>,
+    },
+    StructOps(bpf_link_info_struct_ops),
+    Netfilter(bpf_link_info_netfilter),
+    Tcx(bpf_link_info_tcx),
+    UprobeMulti {
+        info: bpf_link_info_uprobe_multi,
+        path: String,
+        offsets: Vec<u64>,
+        ref_ctr_offsets: Vec<u64>,
+        cookies: Vec<u64>,
+    },
+    Netkit(bpf_link_info_netkit),
+    Sockmap(bpf_link_info_sockmap),
+    /// A link type newer than the bindings.
+    Unknown,
+}
+
+// Written by hand because the iter and perf event infos contain unions,
+// which bindgen does not derive `Debug` for; those print their union-free
+// fields only.
+impl fmt::Debug for LinkDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RawTracepoint { info, tp_name } => f
+                .debug_struct("RawTracepoint")
+                .field("info", info)
+                .field("tp_name", tp_name)
+                .finish(),
+            Self::Tracing(info) => f.debug_tuple("Tracing").field(info).finish(),
+            Self::Cgroup(info) => f.debug_tuple("Cgroup").field(info).finish(),
+            Self::Iter { info, target_name } => f
+                .debug_struct("Iter")
+                .field("target_name_len", &info.target_name_len)
+                .field("target_name", target_name)
+                .finish_non_exhaustive(),
+            Self::Netns(info) => f.debug_tuple("Netns").field(info).finish(),
+            Self::Xdp(info) => f.debug_tuple("Xdp").field(info).finish(),
+            Self::PerfEvent { info, name } => f
+                .debug_struct("PerfEvent")
+                .field("type_", &info.type_)
+                .field("name", name)
+                .finish_non_exhaustive(),
+            Self::KprobeMulti {
+                info,
+                addrs,
+                cookies,
+            } => f
+                .debug_struct("KprobeMulti")
+                .field("info", info)
+                .field("addrs", addrs)
+                .field("cookies", cookies)
+                .finish(),
+            Self::StructOps(info) => f.debug_tuple("StructOps").field(info).finish(),
+            Self::Netfilter(info) => f.debug_tuple("Netfilter").field(info).finish(),
+            Self::Tcx(info) => f.debug_tuple("Tcx").field(info).finish(),
+            Self::UprobeMulti {
+                info,
+                path,
+                offsets,
+                ref_ctr_offsets,
+                cookies,
+            } => f
+                .debug_struct("UprobeMulti")
+                .field("info", info)
+                .field("path", path)
+                .field("offsets", offsets)
+                .field("ref_ctr_offsets", ref_ctr_offsets)
+                .field("cookies", cookies)
+                .finish(),
+            Self::Netkit(info) => f.debug_tuple("Netkit").field(info).finish(),
+            Self::Sockmap(info) => f.debug_tuple("Sockmap").field(info).finish(),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// A link's `bpf_link_info`, decoded by link type.
+#[derive(Debug, Clone)]
+pub struct LinkInfo {
+    pub type_: LinkType,
+    pub id: u32,
+    pub prog_id: u32,
+    pub details: LinkDetails,
+}
+
+impl LinkInfo {
+    /// Retrieves the info of the link `fd`.
+    pub fn from_fd(fd: BorrowedFd<'_>) -> io::Result<Self> {
+        let mut info = bpf_link_info::default();
+        get_info(fd, &mut info)?;
+
+        // The first call reported the lengths, or nothing for strings the
+        // kernel does not measure; the second fills in the buffers.
+        let details = unsafe {
+            match info.type_ {
+                BPF_LINK_TYPE_RAW_TRACEPOINT => {
+                    let len = info.raw_tracepoint().tp_name_len;
+                    let (buf, ptr) = buffer::<u8>(len);
+                    info.raw_tracepoint_mut().tp_name = ptr;
+                    get_info(fd, &mut info)?;
+                    LinkDetails::RawTracepoint {
+                        info: *info.raw_tracepoint(),
+                        tp_name: string(&buf),
+                    }
+                }
+                BPF_LINK_TYPE_TRACING => LinkDetails::Tracing(*info.tracing()),
+                BPF_LINK_TYPE_CGROUP => LinkDetails::Cgroup(*info.cgroup()),
+                BPF_LINK_TYPE_ITER => {
+                    let len = info.iter().target_name_len;
+                    let (buf, ptr) = buffer::<u8>(len);
+                    info.iter_mut().target_name = ptr;
+                    get_info(fd, &mut info)?;
+                    LinkDetails::Iter {
+                        info: *info.iter(),
+                        target_name: string(&buf),
+                    }
+                }
+                BPF_LINK_TYPE_NETNS => LinkDetails::Netns(*info.netns()),
+                BPF_LINK_TYPE_XDP => LinkDetails::Xdp(*info.xdp()),
+                BPF_LINK_TYPE_PERF_EVENT => {
+                    let (buf, ptr) = buffer::<u8>(PATH_MAX);
+                    let perf_event = info.perf_event_mut();
+                    match perf_event.type_ {
+                        BPF_PERF_EVENT_UPROBE | BPF_PERF_EVENT_URETPROBE => {
+                            let uprobe = perf_event.uprobe_mut();
+                            (uprobe.file_name, uprobe.name_len) = (ptr, PATH_MAX);
+                        }
+                        BPF_PERF_EVENT_KPROBE | BPF_PERF_EVENT_KRETPROBE => {
+                            let kprobe = perf_event.kprobe_mut();
+                            (kprobe.func_name, kprobe.name_len) = (ptr, PATH_MAX);
+                        }
+                        BPF_PERF_EVENT_TRACEPOINT => {
+                            let tracepoint = perf_event.tracepoint_mut();
+                            (tracepoint.tp_name, tracepoint.name_len) = (ptr, PATH_MAX);
+                        }
+                        _ => {}
+                    }
+                    get_info(fd, &mut info)?;
+                    LinkDetails::PerfEvent {
+                        info: *info.perf_event(),
+                        name: string(&buf),
+                    }
+                }
+                BPF_LINK_TYPE_KPROBE_MULTI => {
+                    let kprobe_multi = info.kprobe_multi_mut();
+                    let count = kprobe_multi.count;
+                    let (addrs, addrs_ptr) = buffer(count);
+                    let (cookies, cookies_ptr) = buffer(count);
+                    if count > 0 {
+                        (kprobe_multi.addrs, kprobe_multi.cookies) = (addrs_ptr, cookies_ptr);
+                        get_info(fd, &mut info)?;
+                    }
+                    let kprobe_multi = *info.kprobe_multi();
+                    let count = count.min(kprobe_multi.count);
+                    LinkDetails::KprobeMulti {
+                        info: kprobe_multi,
+                        addrs: filled(addrs, count),
+                        cookies: filled(cookies, count),
+                    }
+                }
+                BPF_LINK_TYPE_STRUCT_OPS => LinkDetails::StructOps(*info.struct_ops()),
+                BPF_LINK_TYPE_NETFILTER => LinkDetails::Netfilter(*info.netfilter()),
+                BPF_LINK_TYPE_TCX => LinkDetails::Tcx(*info.tcx()),
+                BPF_LINK_TYPE_UPROBE_MULTI => {
+                    let uprobe_multi = info.uprobe_multi_mut();
+                    let count = uprobe_multi.count;
+                    let (path, path_ptr) = buffer::<u8>(PATH_MAX);
+                    let (offsets, offsets_ptr) = buffer(count);
+                    let (ref_ctr_offsets, ref_ctr_offsets_ptr) = buffer(count);
+                    let (cookies, cookies_ptr) = buffer(count);
+                    (uprobe_multi.path, uprobe_multi.path_size) = (path_ptr, PATH_MAX);
+                    if count > 0 {
+                        uprobe_multi.offsets = offsets_ptr;
+                        uprobe_multi.ref_ctr_offsets = ref_ctr_offsets_ptr;
+                        uprobe_multi.cookies = cookies_ptr;
+                    }
+                    get_info(fd, &mut info)?;
+                    let uprobe_multi = *info.uprobe_multi();
+                    let count = count.min(uprobe_multi.count);
+                    LinkDetails::UprobeMulti {
+                        info: uprobe_multi,
+                        path: string(&path),
+                        offsets: filled(offsets, count),
+                        ref_ctr_offsets: filled(ref_ctr_offsets, count),
+                        cookies: filled(cookies, count),
+                    }
+                }
+                BPF_LINK_TYPE_NETKIT => LinkDetails::Netkit(*info.netkit()),
+                BPF_LINK_TYPE_SOCKMAP => LinkDetails::Sockmap(*info.sockmap()),
+                _ => LinkDetails::Unknown,
+            }
+        };
+
+        Ok(Self {
+            type_: LinkType(info.type_),
+            id: info.id,
+            prog_id: info.prog_id,
+            details,
+        })
+    }
+}
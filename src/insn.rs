@@ -0,0 +1,276 @@
+// src/insn.rs
+
+//! `const fn` equivalents of the kernel's `filter.h` instruction macros.
+//!
+//! The functions keep the macro names and argument order, so programs for
+//! `bpf_prog_load` or `bpf_program__set_insns` can be transcribed from C
+//! directly:
+//!
+//! ```
+//! use libbpf_sys::insn::*;
+//! use libbpf_sys::{BPF_REG_0, BPF_JEQ};
+//!
+//! let prog = [
+//!     BPF_MOV64_IMM(BPF_REG_0, 1),
+//!     BPF_JMP_IMM(BPF_JEQ, BPF_REG_0, 0, 1),
+//!     BPF_MOV64_IMM(BPF_REG_0, 2),
+//!     BPF_EXIT_INSN(),
+//! ];
+//! ```
+//!
+//! Wide `ld_imm64` forms occupy two instruction slots and therefore return
+//! `[bpf_insn; 2]`.
+
+use crate::*;
+
+const fn BPF_OP(op: u32) -> u32 {
+    op & 0xf0
+}
+
+const fn BPF_SRC(src: u32) -> u32 {
+    src & 0x08
+}
+
+const fn BPF_SIZE(size: u32) -> u32 {
+    size & 0x18
+}
+
+/// Packs the `dst_reg:4` and `src_reg:4` bitfields like a C compiler would.
+const fn regs(dst: u32, src: u32) -> __BindgenBitfieldUnit<[u8; 1]> {
+    let (dst, src) = ((dst & 0xf) as u8, (src & 0xf) as u8);
+    __BindgenBitfieldUnit::new([if cfg!(target_endian = "big") {
+        dst << 4 | src
+    } else {
+        src << 4 | dst
+    }])
+}
+
+/// Raw instruction with every field given explicitly.
+pub const fn BPF_RAW_INSN(code: u32, dst: u32, src: u32, off: i16, imm: i32) -> bpf_insn {
+    bpf_insn {
+        code: code as u8,
+        _bitfield_align_1: [],
+        _bitfield_1: regs(dst, src),
+        off,
+        imm,
+    }
+}
+
+/// ALU op on registers: `dst_reg op= src_reg`.
+pub const fn BPF_ALU64_REG_OFF(op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU64 | BPF_OP(op) | BPF_X, dst, src, off, 0)
+}
+
+pub const fn BPF_ALU64_REG(op: u32, dst: u32, src: u32) -> bpf_insn {
+    BPF_ALU64_REG_OFF(op, dst, src, 0)
+}
+
+pub const fn BPF_ALU32_REG_OFF(op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_OP(op) | BPF_X, dst, src, off, 0)
+}
+
+pub const fn BPF_ALU32_REG(op: u32, dst: u32, src: u32) -> bpf_insn {
+    BPF_ALU32_REG_OFF(op, dst, src, 0)
+}
+
+/// ALU op on an immediate: `dst_reg op= imm32`.
+pub const fn BPF_ALU64_IMM_OFF(op: u32, dst: u32, imm: i32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU64 | BPF_OP(op) | BPF_K, dst, 0, off, imm)
+}
+
+pub const fn BPF_ALU64_IMM(op: u32, dst: u32, imm: i32) -> bpf_insn {
+    BPF_ALU64_IMM_OFF(op, dst, imm, 0)
+}
+
+pub const fn BPF_ALU32_IMM_OFF(op: u32, dst: u32, imm: i32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_OP(op) | BPF_K, dst, 0, off, imm)
+}
+
+pub const fn BPF_ALU32_IMM(op: u32, dst: u32, imm: i32) -> bpf_insn {
+    BPF_ALU32_IMM_OFF(op, dst, imm, 0)
+}
+
+/// Endianness conversion: `cpu_to_{l,b}e()`, `{l,b}e_to_cpu()`.
+pub const fn BPF_ENDIAN(ty: u32, dst: u32, len: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_END | BPF_SRC(ty), dst, 0, 0, len)
+}
+
+/// Unconditional byte swap: `bswap16/32/64`.
+pub const fn BPF_BSWAP(dst: u32, len: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU64 | BPF_END | BPF_SRC(BPF_TO_LE), dst, 0, 0, len)
+}
+
+/// Short form of mov: `dst_reg = src_reg`.
+pub const fn BPF_MOV64_REG(dst: u32, src: u32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU64 | BPF_MOV | BPF_X, dst, src, 0, 0)
+}
+
+pub const fn BPF_MOV32_REG(dst: u32, src: u32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_MOV | BPF_X, dst, src, 0, 0)
+}
+
+/// Special form of mov, used to zero-extend the upper 32 bits.
+pub const fn BPF_ZEXT_REG(dst: u32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_MOV | BPF_X, dst, dst, 0, 1)
+}
+
+/// Short form of mov: `dst_reg = imm32`.
+pub const fn BPF_MOV64_IMM(dst: u32, imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU64 | BPF_MOV | BPF_K, dst, 0, 0, imm)
+}
+
+pub const fn BPF_MOV32_IMM(dst: u32, imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_MOV | BPF_K, dst, 0, 0, imm)
+}
+
+/// Short form of movsx: `dst_reg = (s8,s16,s32)src_reg`.
+pub const fn BPF_MOVSX64_REG(dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU64 | BPF_MOV | BPF_X, dst, src, off, 0)
+}
+
+pub const fn BPF_MOVSX32_REG(dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ALU | BPF_MOV | BPF_X, dst, src, off, 0)
+}
+
+/// Wide `ld_imm64` instruction with every field of both halves given.
+pub const fn BPF_LD_IMM64_RAW_FULL(
+    dst: u32,
+    src: u32,
+    off1: i16,
+    off2: i16,
+    imm1: i32,
+    imm2: i32,
+) -> [bpf_insn; 2] {
+    [
+        BPF_RAW_INSN(BPF_LD | BPF_DW | BPF_IMM, dst, src, off1, imm1),
+        BPF_RAW_INSN(0, 0, 0, off2, imm2),
+    ]
+}
+
+pub const fn BPF_LD_IMM64_RAW(dst: u32, src: u32, imm: u64) -> [bpf_insn; 2] {
+    BPF_LD_IMM64_RAW_FULL(dst, src, 0, 0, imm as u32 as i32, (imm >> 32) as u32 as i32)
+}
+
+pub const fn BPF_LD_IMM64(dst: u32, imm: u64) -> [bpf_insn; 2] {
+    BPF_LD_IMM64_RAW(dst, 0, imm)
+}
+
+/// Pseudo `ld_imm64` referring to a process-local map fd.
+pub const fn BPF_LD_MAP_FD(dst: u32, map_fd: i32) -> [bpf_insn; 2] {
+    BPF_LD_IMM64_RAW(dst, BPF_PSEUDO_MAP_FD, map_fd as u32 as u64)
+}
+
+pub const fn BPF_LD_MAP_VALUE(dst: u32, map_fd: i32, value_off: i32) -> [bpf_insn; 2] {
+    BPF_LD_IMM64_RAW_FULL(dst, BPF_PSEUDO_MAP_VALUE, 0, 0, map_fd, value_off)
+}
+
+/// Direct packet access: `R0 = *(uint *) (skb->data + imm32)`.
+pub const fn BPF_LD_ABS(size: u32, imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_LD | BPF_SIZE(size) | BPF_ABS, 0, 0, 0, imm)
+}
+
+/// Indirect packet access: `R0 = *(uint *) (skb->data + src_reg + imm32)`.
+pub const fn BPF_LD_IND(size: u32, src: u32, imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_LD | BPF_SIZE(size) | BPF_IND, 0, src, 0, imm)
+}
+
+/// Memory load: `dst_reg = *(uint *) (src_reg + off16)`.
+pub const fn BPF_LDX_MEM(size: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_LDX | BPF_SIZE(size) | BPF_MEM, dst, src, off, 0)
+}
+
+/// Sign-extending memory load: `dst_reg = *(signed size *) (src_reg + off16)`.
+pub const fn BPF_LDX_MEMSX(size: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_LDX | BPF_SIZE(size) | BPF_MEMSX, dst, src, off, 0)
+}
+
+/// Memory store: `*(uint *) (dst_reg + off16) = src_reg`.
+pub const fn BPF_STX_MEM(size: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_STX | BPF_SIZE(size) | BPF_MEM, dst, src, off, 0)
+}
+
+/// Atomic operation, selected by `op`:
+///
+/// ```text
+///   BPF_ADD                  *(uint *) (dst_reg + off16) += src_reg
+///   BPF_AND                  *(uint *) (dst_reg + off16) &= src_reg
+///   BPF_OR                   *(uint *) (dst_reg + off16) |= src_reg
+///   BPF_XOR                  *(uint *) (dst_reg + off16) ^= src_reg
+///   BPF_ADD | BPF_FETCH      src_reg = atomic_fetch_add(dst_reg + off16, src_reg);
+///   BPF_AND | BPF_FETCH      src_reg = atomic_fetch_and(dst_reg + off16, src_reg);
+///   BPF_OR | BPF_FETCH       src_reg = atomic_fetch_or(dst_reg + off16, src_reg);
+///   BPF_XOR | BPF_FETCH      src_reg = atomic_fetch_xor(dst_reg + off16, src_reg);
+///   BPF_XCHG                 src_reg = atomic_xchg(dst_reg + off16, src_reg)
+///   BPF_CMPXCHG              r0 = atomic_cmpxchg(dst_reg + off16, r0, src_reg)
+///   BPF_LOAD_ACQ             dst_reg = smp_load_acquire(src_reg + off16)
+///   BPF_STORE_REL            smp_store_release(dst_reg + off16, src_reg)
+/// ```
+pub const fn BPF_ATOMIC_OP(size: u32, op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(
+        BPF_STX | BPF_SIZE(size) | BPF_ATOMIC,
+        dst,
+        src,
+        off,
+        op as i32,
+    )
+}
+
+/// Legacy alias for `BPF_ATOMIC_OP(size, BPF_ADD, dst, src, off)`.
+pub const fn BPF_STX_XADD(size: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_ATOMIC_OP(size, BPF_ADD, dst, src, off)
+}
+
+/// Memory store: `*(uint *) (dst_reg + off16) = imm32`.
+pub const fn BPF_ST_MEM(size: u32, dst: u32, off: i16, imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_ST | BPF_SIZE(size) | BPF_MEM, dst, 0, off, imm)
+}
+
+/// Conditional jump against a register: `if (dst_reg 'op' src_reg) goto pc + off16`.
+pub const fn BPF_JMP_REG(op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_OP(op) | BPF_X, dst, src, off, 0)
+}
+
+/// Conditional jump against an immediate: `if (dst_reg 'op' imm32) goto pc + off16`.
+pub const fn BPF_JMP_IMM(op: u32, dst: u32, imm: i32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_OP(op) | BPF_K, dst, 0, off, imm)
+}
+
+/// Like [`BPF_JMP_REG`], but with 32-bit wide operands for comparison.
+pub const fn BPF_JMP32_REG(op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP32 | BPF_OP(op) | BPF_X, dst, src, off, 0)
+}
+
+/// Like [`BPF_JMP_IMM`], but with 32-bit wide operands for comparison.
+pub const fn BPF_JMP32_IMM(op: u32, dst: u32, imm: i32, off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP32 | BPF_OP(op) | BPF_K, dst, 0, off, imm)
+}
+
+/// Unconditional jump: `goto pc + off16`.
+pub const fn BPF_JMP_A(off: i16) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_JA, 0, 0, off, 0)
+}
+
+/// Unconditional long jump: `gotol pc + imm32`.
+pub const fn BPF_JMP32_A(imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP32 | BPF_JA, 0, 0, 0, imm)
+}
+
+/// Call to a BPF subprogram, `imm` instructions away.
+pub const fn BPF_CALL_REL(imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_CALL, 0, BPF_PSEUDO_CALL, 0, imm)
+}
+
+/// Kfunc call, by BTF id and module BTF fd index.
+pub const fn BPF_CALL_KFUNC(off: i16, imm: i32) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_CALL, 0, BPF_PSEUDO_KFUNC_CALL, off, imm)
+}
+
+/// Helper call, by `BPF_FUNC_*` id.
+pub const fn BPF_EMIT_CALL(func: bpf_func_id) -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_CALL, 0, 0, 0, func as i32)
+}
+
+/// Program exit.
+pub const fn BPF_EXIT_INSN() -> bpf_insn {
+    BPF_RAW_INSN(BPF_JMP | BPF_EXIT, 0, 0, 0, 0)
+}
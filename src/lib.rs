@@ -6,14 +6,83 @@
 
 #[allow(clippy::all)]
 mod bindings {
-    #[cfg(all(feature = "bindgen", not(feature = "bindgen-source")))]
+    #[cfg(feature = "size-t-is-usize")]
+    include!(concat!(env!("OUT_DIR"), "/bindings_usize.rs"));
+    #[cfg(all(
+        feature = "bindgen",
+        not(feature = "bindgen-source"),
+        not(feature = "size-t-is-usize")
+    ))]
     include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
-    #[cfg(any(not(feature = "bindgen"), feature = "bindgen-source"))]
+    #[cfg(all(
+        any(not(feature = "bindgen"), feature = "bindgen-source"),
+        not(all(target_os = "android", target_arch = "aarch64")),
+        not(feature = "size-t-is-usize")
+    ))]
     include!("bindings.rs");
+    #[cfg(all(
+        any(not(feature = "bindgen"), feature = "bindgen-source"),
+        target_os = "android",
+        target_arch = "aarch64",
+        not(feature = "size-t-is-usize")
+    ))]
+    include!("bindings_android.rs");
 }
 
+// The `size-t-is-usize` feature swaps `c_ulong` for `usize` in every
+// signature taking a `size_t`, which is only sound if the two are the same
+// type as far as the C ABI is concerned.
+const _: () = assert!(
+    std::mem::size_of::<std::os::raw::c_ulong>() == std::mem::size_of::<usize>()
+        && std::mem::align_of::<std::os::raw::c_ulong>() == std::mem::align_of::<usize>()
+);
+
 pub use bindings::*;
 
+// The bindings, and the stable names derived from them, live in the crate
+// root. Everything built on top of them gets a public module of its own.
+mod anon_types;
+
+pub use anon_types::*;
+
+/// Applies `#[cfg(not(feature = "syscall-only"))]` to each item, for the
+/// modules that call into libbpf and so need it linked.
+macro_rules! needs_libbpf {
+    ($($item:item)*) => {
+        $(
+            #[cfg(not(feature = "syscall-only"))]
+            $item
+        )*
+    };
+}
+
+pub mod asm;
+pub mod btf_accessors;
+pub mod disasm;
+pub mod headers;
+pub mod insn;
+pub mod opts;
+#[cfg(feature = "perf-event")]
+pub mod perf_event;
+#[cfg(feature = "syscall")]
+pub mod syscall;
+
+needs_libbpf! {
+    pub mod btf_dump_writer;
+    pub mod error;
+    pub mod info;
+    pub mod loaded;
+    pub mod percpu;
+    pub mod perfbuf;
+    pub mod print;
+    pub mod prog_handler;
+    pub mod ringbuf;
+    pub mod skeleton;
+    pub mod type_names;
+    pub mod verifier_log;
+    pub mod version;
+}
+
 #[cfg(feature = "vendored-libbpf")]
 macro_rules! header {
     ($file:literal) => {
@@ -21,11 +90,21 @@ macro_rules! header {
     };
 }
 
+#[cfg(feature = "vendored-libbpf")]
+macro_rules! uapi_header {
+    ($file:literal) => {
+        (
+            $file,
+            include_str!(concat!("../libbpf/include/uapi/linux/", $file)),
+        )
+    };
+}
+
 /// Vendored libbpf headers
 ///
 /// Tuple format is: (header filename, header contents)
 #[cfg(feature = "vendored-libbpf")]
-pub const API_HEADERS: [(&str, &str); 10] = [
+pub const API_HEADERS: [(&str, &str); 12] = [
     header!("bpf.h"),
     header!("libbpf.h"),
     header!("btf.h"),
@@ -35,5 +114,19 @@ pub const API_HEADERS: [(&str, &str); 10] = [
     header!("bpf_endian.h"),
     header!("bpf_core_read.h"),
     header!("libbpf_common.h"),
+    header!("libbpf_legacy.h"),
+    header!("libbpf_version.h"),
     header!("usdt.bpf.h"),
 ];
+
+/// Vendored uapi headers, included by the libbpf headers as `<linux/...>`
+///
+/// Tuple format is: (header filename, header contents)
+#[cfg(feature = "vendored-libbpf")]
+pub const UAPI_HEADERS: [(&str, &str); 5] = [
+    uapi_header!("bpf.h"),
+    uapi_header!("bpf_common.h"),
+    uapi_header!("btf.h"),
+    uapi_header!("if_link.h"),
+    uapi_header!("perf_event.h"),
+];
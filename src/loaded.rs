@@ -0,0 +1,136 @@
+// src/loaded.rs
+
+//! Walking every program, map, BTF object and link loaded in the system.
+//!
+//! Objects are enumerated by id with `bpf_*_get_next_id`, like `bpftool
+//! prog show` and friends do, then opened with `bpf_*_get_fd_by_id_opts`
+//! and described with the matching [`info`](crate::info::ProgInfo) type. Objects
+//! freed between those steps are skipped. This needs `CAP_SYS_ADMIN`.
+//!
+//! ```no_run
+//! for prog in libbpf_sys::loaded::loaded_progs() {
+//!     let prog = prog.unwrap();
+//!     println!("{}: {}", prog.id, prog.info.name());
+//! }
+//! ```
+
+use std::io;
+use std::os::fd::AsFd;
+use std::os::fd::BorrowedFd;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::raw::c_int;
+
+use crate::error::ENOENT;
+use crate::info::BtfInfo;
+use crate::info::LinkInfo;
+use crate::info::MapInfo;
+use crate::info::ProgInfo;
+use crate::opts::LibbpfOpts;
+use crate::*;
+
+type GetNextIdFn = unsafe extern "C" fn(__u32, *mut __u32) -> c_int;
+type GetFdByIdFn = unsafe extern "C" fn(__u32, *const bpf_get_fd_by_id_opts) -> c_int;
+type InfoFn<I> = fn(BorrowedFd<'_>) -> io::Result<I>;
+
+/// A loaded object, opened, with its info.
+#[derive(Debug)]
+pub struct LoadedObject<I> {
+    pub id: u32,
+    pub fd: OwnedFd,
+    pub info: I,
+}
+
+/// An iterator over the loaded objects of one kind, in id order.
+///
+/// Errors opening or describing an object are yielded in its place, and
+/// iteration continues past them; an error enumerating ids, such as `EPERM`,
+/// ends iteration after being yielded.
+pub struct LoadedObjects<I> {
+    id: Option<u32>,
+    opts: bpf_get_fd_by_id_opts,
+    get_next_id: GetNextIdFn,
+    get_fd_by_id: GetFdByIdFn,
+    info: InfoFn<I>,
+}
+
+impl<I> LoadedObjects<I> {
+    fn new(get_next_id: GetNextIdFn, get_fd_by_id: GetFdByIdFn, info: InfoFn<I>) -> Self {
+        Self {
+            id: Some(0),
+            opts: bpf_get_fd_by_id_opts::new_sized(),
+            get_next_id,
+            get_fd_by_id,
+            info,
+        }
+    }
+
+    /// Sets the `open_flags` objects are opened with, such as `BPF_F_RDONLY`
+    /// for maps the caller may not write to.
+    pub fn open_flags(mut self, flags: u32) -> Self {
+        self.opts.open_flags = flags;
+        self
+    }
+}
+
+impl<I> Iterator for LoadedObjects<I> {
+    type Item = io::Result<LoadedObject<I>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut id = 0;
+            let ret = unsafe { (self.get_next_id)(self.id?, &mut id) };
+            if ret < 0 {
+                self.id = None;
+                return (ret != -ENOENT).then(|| Err(io::Error::from_raw_os_error(-ret)));
+            }
+            self.id = Some(id);
+
+            let fd = unsafe { (self.get_fd_by_id)(id, &self.opts) };
+            if fd == -ENOENT {
+                // Freed since its id was returned.
+                continue;
+            } else if fd < 0 {
+                return Some(Err(io::Error::from_raw_os_error(-fd)));
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            return Some((self.info)(fd.as_fd()).map(|info| LoadedObject { id, fd, info }));
+        }
+    }
+}
+
+/// Iterates over all loaded programs.
+pub fn loaded_progs() -> LoadedObjects<ProgInfo> {
+    LoadedObjects::new(
+        bpf_prog_get_next_id,
+        bpf_prog_get_fd_by_id_opts,
+        ProgInfo::from_fd,
+    )
+}
+
+/// Iterates over all maps.
+pub fn loaded_maps() -> LoadedObjects<MapInfo> {
+    LoadedObjects::new(
+        bpf_map_get_next_id,
+        bpf_map_get_fd_by_id_opts,
+        MapInfo::from_fd,
+    )
+}
+
+/// Iterates over all BTF objects, including the kernel's.
+pub fn loaded_btfs() -> LoadedObjects<BtfInfo> {
+    LoadedObjects::new(
+        bpf_btf_get_next_id,
+        bpf_btf_get_fd_by_id_opts,
+        BtfInfo::from_fd,
+    )
+}
+
+/// Iterates over all links.
+pub fn loaded_links() -> LoadedObjects<LinkInfo> {
+    LoadedObjects::new(
+        bpf_link_get_next_id,
+        bpf_link_get_fd_by_id_opts,
+        LinkInfo::from_fd,
+    )
+}
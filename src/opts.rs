@@ -0,0 +1,58 @@
+// src/opts.rs
+
+//! Sized construction of libbpf "opts" structs.
+//!
+//! libbpf extends its option structs in a backwards compatible way by
+//! checking their leading `sz` field, and treats `sz == 0` as "no options
+//! given". The C API hides this behind the `LIBBPF_OPTS` macro; the derived
+//! `Default` impls in the bindings leave `sz` at zero, so options set on a
+//! defaulted struct are silently ignored.
+
+use crate::*;
+
+/// A libbpf option struct whose first field is its own size.
+///
+/// Implemented by the build script for every struct in the bindings that
+/// starts with `sz`, except the skeletons.
+pub trait LibbpfOpts: Default {
+    /// Returns a zeroed instance with `sz` set, like `LIBBPF_OPTS` in C.
+    fn new_sized() -> Self;
+}
+
+macro_rules! impl_libbpf_opts {
+    ($name:ident) => {
+        impl LibbpfOpts for $name {
+            fn new_sized() -> Self {
+                let mut opts = $name::default();
+                opts.sz = ::std::mem::size_of::<$name>() as size_t;
+                opts
+            }
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/opts.rs"));
+
+/// Creates a libbpf option struct with `sz` set and the given fields
+/// overridden, mirroring the `LIBBPF_OPTS` macro from `libbpf_common.h`.
+///
+/// ```
+/// use libbpf_sys::{bpf_object_open_opts, libbpf_opts};
+///
+/// let opts = libbpf_opts!(bpf_object_open_opts {
+///     relaxed_maps: true,
+/// });
+/// assert_eq!(opts.sz as usize, std::mem::size_of::<bpf_object_open_opts>());
+/// ```
+#[macro_export]
+macro_rules! libbpf_opts {
+    ($ty:ty { $($field:ident : $value:expr),* $(,)? }) => {{
+        #[allow(unused_mut)]
+        let mut opts = <$ty as $crate::opts::LibbpfOpts>::new_sized();
+        $(opts.$field = $value;)*
+        opts
+    }};
+    ($ty:ty) => {
+        <$ty as $crate::opts::LibbpfOpts>::new_sized()
+    };
+}
@@ -0,0 +1,362 @@
+// src/percpu.rs
+
+//! Values of per-CPU maps.
+//!
+//! Looking up a key in a `BPF_MAP_TYPE_PERCPU_HASH`, `PERCPU_ARRAY`,
+//! `LRU_PERCPU_HASH` or `PERCPU_CGROUP_STORAGE` map returns one value per
+//! *possible* CPU, each padded to a multiple of 8 bytes, so the buffer
+//! passed to `bpf_map_lookup_elem` or `bpf_map__lookup_elem` must be
+//! [`percpu_value_size`] bytes long. The kernel writes past a smaller one.
+//! [`PerCpuValues`] is such a buffer, and [`PerCpuMap`] sizes and checks
+//! them from the map's own info.
+//!
+//! Possible CPUs include those offline or not yet plugged in; slot `i`
+//! holds the value of the `i`th CPU in [`possible_cpus`], whether or not
+//! it is in [`online_cpus`].
+//!
+//! ```no_run
+//! # fn main() -> std::io::Result<()> {
+//! # let fd: std::os::fd::OwnedFd = unimplemented!();
+//! use std::os::fd::AsFd;
+//!
+//! let map = libbpf_sys::percpu::PerCpuMap::from_fd(fd.as_fd())?;
+//! if let Some(values) = map.lookup(&0u32.to_ne_bytes())? {
+//!     let total: u64 = values
+//!         .iter()
+//!         .map(|value| u64::from_ne_bytes(value.try_into().unwrap()))
+//!         .sum();
+//!     println!("{total}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::io;
+use std::mem;
+use std::os::fd::AsRawFd;
+use std::os::fd::BorrowedFd;
+
+use crate::error::ENOENT;
+use crate::error::ENOSPC;
+use crate::info::MapInfo;
+use crate::opts::LibbpfOpts;
+use crate::type_names::MapType;
+use crate::*;
+
+/// The number of possible CPUs, as `libbpf_num_possible_cpus` reports it.
+pub fn num_possible_cpus() -> io::Result<usize> {
+    let cpus = unsafe { libbpf_num_possible_cpus() };
+    if cpus < 0 {
+        Err(io::Error::from_raw_os_error(-cpus))
+    } else {
+        Ok(cpus as usize)
+    }
+}
+
+/// The ids of the possible CPUs, from `/sys/devices/system/cpu/possible`.
+pub fn possible_cpus() -> io::Result<Vec<u32>> {
+    parse_cpu_list(&fs::read_to_string("/sys/devices/system/cpu/possible")?)
+}
+
+/// The ids of the online CPUs, from `/sys/devices/system/cpu/online`.
+pub fn online_cpus() -> io::Result<Vec<u32>> {
+    parse_cpu_list(&fs::read_to_string("/sys/devices/system/cpu/online")?)
+}
+
+/// Parses a CPU list such as `0-3,8`.
+fn parse_cpu_list(list: &str) -> io::Result<Vec<u32>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("bad CPU list {list:?}"));
+    let mut cpus = Vec::new();
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        let (first, last) = range.split_once('-').unwrap_or((range, range));
+        let first: u32 = first.parse().map_err(|_| invalid())?;
+        let last = last.parse().map_err(|_| invalid())?;
+        cpus.extend(first..=last);
+    }
+    Ok(cpus)
+}
+
+/// The size of one CPU's slot for a value of `value_size` bytes.
+fn stride(value_size: usize) -> usize {
+    value_size.next_multiple_of(8)
+}
+
+/// The size of the buffer a lookup in a per-CPU map with values of
+/// `value_size` bytes fills in.
+pub fn percpu_value_size(value_size: usize) -> io::Result<usize> {
+    Ok(stride(value_size) * num_possible_cpus()?)
+}
+
+/// One value per possible CPU, laid out as the kernel reads and writes
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerCpuValues {
+    value_size: usize,
+    data: Vec<u8>,
+}
+
+impl PerCpuValues {
+    /// Zeroed values of `value_size` bytes for every possible CPU.
+    pub fn new(value_size: usize) -> io::Result<Self> {
+        Ok(Self::with_cpus(value_size, num_possible_cpus()?))
+    }
+
+    /// Zeroed values of `value_size` bytes for `cpus` CPUs.
+    pub fn with_cpus(value_size: usize, cpus: usize) -> Self {
+        Self {
+            value_size,
+            data: vec![0; stride(value_size) * cpus],
+        }
+    }
+
+    /// The size of each value, without padding.
+    pub fn value_size(&self) -> usize {
+        self.value_size
+    }
+
+    /// The number of CPUs with a value.
+    pub fn len(&self) -> usize {
+        self.data
+            .len()
+            .checked_div(stride(self.value_size))
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The value of the CPU in slot `cpu`.
+    pub fn get(&self, cpu: usize) -> Option<&[u8]> {
+        let start = cpu.checked_mul(stride(self.value_size))?;
+        self.data.get(start..start.checked_add(self.value_size)?)
+    }
+
+    /// The value of the CPU in slot `cpu`, mutably.
+    pub fn get_mut(&mut self, cpu: usize) -> Option<&mut [u8]> {
+        let start = cpu.checked_mul(stride(self.value_size))?;
+        self.data.get_mut(start..start.checked_add(self.value_size)?)
+    }
+
+    /// Iterates over the values in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        let value_size = self.value_size;
+        self.data
+            .chunks_exact(stride(value_size).max(1))
+            .map(move |slot| &slot[..value_size])
+    }
+
+    /// The padded buffer, as passed to `bpf_map_update_elem`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The padded buffer, as passed to `bpf_map_lookup_elem`.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+/// A per-CPU map, with the sizes to check keys and values against.
+#[derive(Debug, Clone, Copy)]
+pub struct PerCpuMap<'fd> {
+    fd: BorrowedFd<'fd>,
+    key_size: usize,
+    value_size: usize,
+    max_entries: u32,
+    cpus: usize,
+}
+
+impl<'fd> PerCpuMap<'fd> {
+    /// Reads the key and value sizes of the map `fd`, failing with
+    /// `InvalidInput` if it is not a per-CPU map.
+    pub fn from_fd(fd: BorrowedFd<'fd>) -> io::Result<Self> {
+        let info = MapInfo::from_fd(fd)?.info;
+        match info.type_ {
+            BPF_MAP_TYPE_PERCPU_HASH
+            | BPF_MAP_TYPE_PERCPU_ARRAY
+            | BPF_MAP_TYPE_LRU_PERCPU_HASH
+            | BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE => {}
+            type_ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is not a per-CPU map type", MapType(type_)),
+                ))
+            }
+        }
+        Ok(Self {
+            fd,
+            key_size: info.key_size as usize,
+            value_size: info.value_size as usize,
+            max_entries: info.max_entries,
+            cpus: num_possible_cpus()?,
+        })
+    }
+
+    pub fn key_size(&self) -> usize {
+        self.key_size
+    }
+
+    pub fn value_size(&self) -> usize {
+        self.value_size
+    }
+
+    /// Zeroed values for this map.
+    pub fn values(&self) -> PerCpuValues {
+        PerCpuValues::with_cpus(self.value_size, self.cpus)
+    }
+
+    fn check_key(&self, key: &[u8]) -> io::Result<()> {
+        if key.len() != self.key_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("key is {} bytes, map has {}", key.len(), self.key_size),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_values(&self, values: &PerCpuValues) -> io::Result<()> {
+        if values.value_size != self.value_size || values.len() != self.cpus {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{} values of {} bytes, map has {} of {}",
+                    values.len(),
+                    values.value_size,
+                    self.cpus,
+                    self.value_size
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Looks up `key`, returning `None` if it is not in the map.
+    pub fn lookup(&self, key: &[u8]) -> io::Result<Option<PerCpuValues>> {
+        self.check_key(key)?;
+        let mut values = self.values();
+        let ret = unsafe {
+            bpf_map_lookup_elem(
+                self.fd.as_raw_fd(),
+                key.as_ptr().cast(),
+                values.data.as_mut_ptr().cast(),
+            )
+        };
+        match ret {
+            0 => Ok(Some(values)),
+            ret if ret == -ENOENT => Ok(None),
+            ret => Err(io::Error::from_raw_os_error(-ret)),
+        }
+    }
+
+    /// Sets the values of `key`, with `flags` such as `BPF_NOEXIST`.
+    pub fn update(&self, key: &[u8], values: &PerCpuValues, flags: u64) -> io::Result<()> {
+        self.check_key(key)?;
+        self.check_values(values)?;
+        let ret = unsafe {
+            bpf_map_update_elem(
+                self.fd.as_raw_fd(),
+                key.as_ptr().cast(),
+                values.data.as_ptr().cast(),
+                flags,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::from_raw_os_error(-ret));
+        }
+        Ok(())
+    }
+
+    /// Reads every entry with `bpf_map_lookup_batch`, `batch_size` at a
+    /// time. Hash buckets larger than `batch_size` make the kernel fail
+    /// with `ENOSPC`, in which case the batch is retried at twice the size,
+    /// up to the map's `max_entries`, after which the error is returned.
+    pub fn lookup_batch(&self, batch_size: u32) -> io::Result<Vec<(Vec<u8>, PerCpuValues)>> {
+        let opts = bpf_map_batch_opts::new_sized();
+        let value_len = stride(self.value_size) * self.cpus;
+        // Hash maps use a bucket index as the batch token, arrays a key.
+        let token_size = self.key_size.max(mem::size_of::<u64>());
+        let mut in_batch = vec![0u8; token_size];
+        let mut out_batch = vec![0u8; token_size];
+        let mut first = true;
+        let mut batch_size = batch_size.max(1);
+        let mut entries = Vec::new();
+        loop {
+            let mut keys = vec![0u8; self.key_size * batch_size as usize];
+            let mut values = vec![0u8; value_len * batch_size as usize];
+            let mut count = batch_size;
+            let ret = unsafe {
+                bpf_map_lookup_batch(
+                    self.fd.as_raw_fd(),
+                    if first {
+                        std::ptr::null_mut()
+                    } else {
+                        in_batch.as_mut_ptr().cast()
+                    },
+                    out_batch.as_mut_ptr().cast(),
+                    keys.as_mut_ptr().cast(),
+                    values.as_mut_ptr().cast(),
+                    &mut count,
+                    &opts,
+                )
+            };
+            if ret == -ENOSPC && count == 0 && batch_size < self.max_entries {
+                batch_size = batch_size.saturating_mul(2).min(self.max_entries);
+                continue;
+            } else if ret < 0 && ret != -ENOENT {
+                return Err(io::Error::from_raw_os_error(-ret));
+            }
+
+            let count = count as usize;
+            let keys = keys.chunks_exact(self.key_size.max(1)).take(count);
+            let values = values.chunks_exact(value_len.max(1)).take(count);
+            entries.extend(keys.zip(values).map(|(key, data)| {
+                let values = PerCpuValues {
+                    value_size: self.value_size,
+                    data: data.to_vec(),
+                };
+                (key.to_vec(), values)
+            }));
+            if ret == -ENOENT {
+                return Ok(entries);
+            }
+            mem::swap(&mut in_batch, &mut out_batch);
+            first = false;
+        }
+    }
+
+    /// Sets the values of every key in `entries` with a single
+    /// `bpf_map_update_batch`, with `elem_flags` such as `BPF_NOEXIST`.
+    pub fn update_batch(
+        &self,
+        entries: &[(&[u8], &PerCpuValues)],
+        elem_flags: u64,
+    ) -> io::Result<()> {
+        let mut keys = Vec::with_capacity(self.key_size * entries.len());
+        let mut values = Vec::new();
+        for (key, value) in entries {
+            self.check_key(key)?;
+            self.check_values(value)?;
+            keys.extend_from_slice(key);
+            values.extend_from_slice(&value.data);
+        }
+        let mut opts = bpf_map_batch_opts::new_sized();
+        opts.elem_flags = elem_flags;
+        let mut count = entries.len() as u32;
+        let ret = unsafe {
+            bpf_map_update_batch(
+                self.fd.as_raw_fd(),
+                keys.as_ptr().cast(),
+                values.as_ptr().cast(),
+                &mut count,
+                &opts,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::from_raw_os_error(-ret));
+        }
+        Ok(())
+    }
+}
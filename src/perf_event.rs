@@ -0,0 +1,423 @@
+// src/perf_event.rs
+
+//! Opening perf events for BPF programs to attach to.
+//!
+//! `bpf_program__attach_perf_event` takes a perf event file descriptor,
+//! which only the raw `perf_event_open(2)` syscall provides.
+//! [`PerfEventBuilder`] fills in a `perf_event_attr` for the common cases
+//! and opens it, on one CPU or on all of them, and [`PerfEvent`] wraps the
+//! `PERF_EVENT_IOC_*` ioctls, whose request codes bindgen cannot translate.
+//!
+//! ```no_run
+//! use libbpf_sys::perf_event::PerfEventBuilder;
+//! use libbpf_sys::*;
+//!
+//! # let prog: *mut bpf_program = std::ptr::null_mut();
+//! let events = PerfEventBuilder::software(PERF_COUNT_SW_CPU_CLOCK)
+//!     .sample_freq(99)
+//!     .open_per_cpu()
+//!     .unwrap();
+//! let links = events
+//!     .into_iter()
+//!     .map(|event| unsafe { event.attach(prog) })
+//!     .collect::<std::io::Result<Vec<_>>>()
+//!     .unwrap();
+//! ```
+
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::mem;
+use std::os::fd::AsFd;
+use std::os::fd::AsRawFd;
+use std::os::fd::BorrowedFd;
+use std::os::fd::FromRawFd;
+use std::os::fd::IntoRawFd;
+use std::os::fd::OwnedFd;
+use std::os::fd::RawFd;
+use std::os::raw::c_int;
+use std::os::raw::c_ulong;
+use std::ptr;
+
+use crate::*;
+
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+mod ioc {
+    pub const NONE: u32 = 1;
+    pub const READ: u32 = 2;
+    pub const WRITE: u32 = 4;
+    pub const SIZE_BITS: u32 = 13;
+}
+
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+mod ioc {
+    pub const NONE: u32 = 0;
+    pub const READ: u32 = 2;
+    pub const WRITE: u32 = 1;
+    pub const SIZE_BITS: u32 = 14;
+}
+
+/// `_IOC(dir, '$', nr, size)`, as in `<asm-generic/ioctl.h>` and its
+/// per-architecture variants.
+const fn perf_ioc(dir: u32, nr: u32, size: usize) -> u32 {
+    (dir << (16 + ioc::SIZE_BITS)) | ((size as u32) << 16) | ((b'$' as u32) << 8) | nr
+}
+
+pub const PERF_EVENT_IOC_ENABLE: u32 = perf_ioc(ioc::NONE, 0, 0);
+pub const PERF_EVENT_IOC_DISABLE: u32 = perf_ioc(ioc::NONE, 1, 0);
+pub const PERF_EVENT_IOC_REFRESH: u32 = perf_ioc(ioc::NONE, 2, 0);
+pub const PERF_EVENT_IOC_RESET: u32 = perf_ioc(ioc::NONE, 3, 0);
+pub const PERF_EVENT_IOC_PERIOD: u32 = perf_ioc(ioc::WRITE, 4, mem::size_of::<__u64>());
+pub const PERF_EVENT_IOC_SET_OUTPUT: u32 = perf_ioc(ioc::NONE, 5, 0);
+pub const PERF_EVENT_IOC_SET_FILTER: u32 = perf_ioc(ioc::WRITE, 6, mem::size_of::<usize>());
+pub const PERF_EVENT_IOC_ID: u32 = perf_ioc(ioc::READ, 7, mem::size_of::<usize>());
+pub const PERF_EVENT_IOC_SET_BPF: u32 = perf_ioc(ioc::WRITE, 8, mem::size_of::<__u32>());
+pub const PERF_EVENT_IOC_PAUSE_OUTPUT: u32 = perf_ioc(ioc::WRITE, 9, mem::size_of::<__u32>());
+pub const PERF_EVENT_IOC_QUERY_BPF: u32 =
+    perf_ioc(ioc::READ | ioc::WRITE, 10, mem::size_of::<usize>());
+pub const PERF_EVENT_IOC_MODIFY_ATTRIBUTES: u32 = perf_ioc(ioc::WRITE, 11, mem::size_of::<usize>());
+
+/// Reads the value of a dynamic PMU's `type` or format file, such as
+/// `/sys/bus/event_source/devices/kprobe/type`.
+fn read_pmu_file(pmu: &str, file: &str) -> io::Result<String> {
+    let path = format!("/sys/bus/event_source/devices/{pmu}/{file}");
+    Ok(fs::read_to_string(path)?.trim().to_owned())
+}
+
+/// The type of the dynamic PMU `pmu`, and the `config` bit that makes it
+/// probe function returns.
+fn probe_pmu(pmu: &str) -> io::Result<(u32, u32)> {
+    let ty = read_pmu_file(pmu, "type")?;
+    // The format reads "config:<bit>".
+    let retprobe = read_pmu_file(pmu, "format/retprobe")?;
+    let bit = retprobe.strip_prefix("config:").map(str::parse);
+    match (ty.parse(), bit) {
+        (Ok(ty), Some(Ok(bit))) => Ok((ty, bit)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unexpected {pmu} PMU type {ty:?} or retprobe format {retprobe:?}"),
+        )),
+    }
+}
+
+/// Builds a `perf_event_attr` and opens it with `perf_event_open(2)`.
+///
+/// Events start disabled unless [`enabled`](Self::enabled) is set, and
+/// are opened with `PERF_FLAG_FD_CLOEXEC`.
+pub struct PerfEventBuilder {
+    attr: perf_event_attr,
+    // Backs `config1` for kprobe and uprobe events.
+    name: Option<CString>,
+}
+
+impl PerfEventBuilder {
+    /// An event of `type_` and `config`, with no sampling set up.
+    pub fn new(type_: perf_type_id, config: u64) -> Self {
+        let mut attr = perf_event_attr {
+            type_,
+            size: mem::size_of::<perf_event_attr>() as u32,
+            config,
+            ..Default::default()
+        };
+        attr.set_disabled(1);
+        Self { attr, name: None }
+    }
+
+    /// A software event such as `PERF_COUNT_SW_CPU_CLOCK`.
+    pub fn software(config: perf_sw_ids) -> Self {
+        Self::new(PERF_TYPE_SOFTWARE, config.into())
+    }
+
+    /// A hardware event such as `PERF_COUNT_HW_CPU_CYCLES`.
+    pub fn hardware(config: perf_hw_id) -> Self {
+        Self::new(PERF_TYPE_HARDWARE, config.into())
+    }
+
+    /// The tracepoint with id `id`, from tracefs'
+    /// `events/<category>/<name>/id`.
+    pub fn tracepoint(id: u64) -> Self {
+        let mut builder = Self::new(PERF_TYPE_TRACEPOINT, id);
+        builder.attr.sample_mut().sample_period = 1;
+        builder
+    }
+
+    /// A kprobe, or kretprobe if `retprobe`, at `offset` into the kernel
+    /// function `func`, created through the `kprobe` PMU.
+    pub fn kprobe(func: &str, offset: u64, retprobe: bool) -> io::Result<Self> {
+        Self::probe("kprobe", func, offset, retprobe)
+    }
+
+    /// A uprobe, or uretprobe if `retprobe`, at file offset `offset` in the
+    /// binary `path`, created through the `uprobe` PMU.
+    pub fn uprobe(path: &str, offset: u64, retprobe: bool) -> io::Result<Self> {
+        Self::probe("uprobe", path, offset, retprobe)
+    }
+
+    fn probe(pmu: &str, name: &str, offset: u64, retprobe: bool) -> io::Result<Self> {
+        let name =
+            CString::new(name).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        let (ty, retprobe_bit) = probe_pmu(pmu)?;
+        let mut builder = Self::new(ty, 0);
+        if retprobe {
+            builder.attr.config |= 1 << retprobe_bit;
+        }
+        // `kprobe_func`/`kprobe_addr` and `uprobe_path`/`probe_offset`
+        // share these fields.
+        builder.attr.config1_mut().config1 = name.as_ptr() as u64;
+        builder.attr.config2_mut().config2 = offset;
+        builder.attr.sample_mut().sample_period = 1;
+        builder.name = Some(name);
+        Ok(builder)
+    }
+
+    /// Samples every `period` events.
+    pub fn sample_period(mut self, period: u64) -> Self {
+        self.attr.set_freq(0);
+        self.attr.sample_mut().sample_period = period;
+        self
+    }
+
+    /// Samples about `freq` times per second.
+    pub fn sample_freq(mut self, freq: u64) -> Self {
+        self.attr.set_freq(1);
+        self.attr.sample_mut().sample_freq = freq;
+        self
+    }
+
+    /// Sets the `PERF_SAMPLE_*` fields recorded with each sample.
+    pub fn sample_type(mut self, sample_type: u64) -> Self {
+        self.attr.sample_type = sample_type;
+        self
+    }
+
+    /// Wakes up readers every `events` samples.
+    pub fn wakeup_events(mut self, events: u32) -> Self {
+        self.attr.wakeup_mut().wakeup_events = events;
+        self
+    }
+
+    /// Whether the event counts as soon as it is opened.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.attr.set_disabled((!enabled).into());
+        self
+    }
+
+    /// Whether child tasks inherit the event.
+    pub fn inherit(mut self, inherit: bool) -> Self {
+        self.attr.set_inherit(inherit.into());
+        self
+    }
+
+    /// Whether events in the kernel are excluded.
+    pub fn exclude_kernel(mut self, exclude: bool) -> Self {
+        self.attr.set_exclude_kernel(exclude.into());
+        self
+    }
+
+    /// Whether events in user space are excluded.
+    pub fn exclude_user(mut self, exclude: bool) -> Self {
+        self.attr.set_exclude_user(exclude.into());
+        self
+    }
+
+    /// The attr built so far.
+    pub fn attr(&self) -> &perf_event_attr {
+        &self.attr
+    }
+
+    /// The attr built so far, for fields without a dedicated setter.
+    pub fn attr_mut(&mut self) -> &mut perf_event_attr {
+        &mut self.attr
+    }
+
+    /// Opens the event for process `pid` on `cpu`, either of which may be
+    /// -1 for any, in the group led by `group`.
+    pub fn open(
+        &self,
+        pid: c_int,
+        cpu: c_int,
+        group: Option<BorrowedFd<'_>>,
+    ) -> io::Result<PerfEvent> {
+        let group_fd = group.map_or(-1, |group| group.as_raw_fd());
+        let ret = unsafe {
+            libc::syscall(
+                libc::SYS_perf_event_open,
+                &self.attr as *const perf_event_attr,
+                pid,
+                cpu,
+                group_fd,
+                PERF_FLAG_FD_CLOEXEC as c_ulong,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(PerfEvent {
+            fd: unsafe { OwnedFd::from_raw_fd(ret as RawFd) },
+            cpu,
+        })
+    }
+
+    /// Opens the event for every process on each possible CPU, as counted
+    /// by `libbpf_num_possible_cpus`, skipping CPUs that are offline.
+    pub fn open_per_cpu(&self) -> io::Result<Vec<PerfEvent>> {
+        let cpus = unsafe { libbpf_num_possible_cpus() };
+        if cpus < 0 {
+            return Err(io::Error::from_raw_os_error(-cpus));
+        }
+        let mut events = Vec::with_capacity(cpus as usize);
+        for cpu in 0..cpus {
+            match self.open(-1, cpu, None) {
+                Ok(event) => events.push(event),
+                Err(err) if err.raw_os_error() == Some(libc::ENODEV) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// An open perf event.
+#[derive(Debug)]
+pub struct PerfEvent {
+    fd: OwnedFd,
+    cpu: c_int,
+}
+
+impl PerfEvent {
+    /// The CPU the event was opened on, or -1 for any.
+    pub fn cpu(&self) -> c_int {
+        self.cpu
+    }
+
+    fn ioctl(&self, request: u32, arg: c_ulong) -> io::Result<c_int> {
+        let ret = unsafe { libc::ioctl(self.fd.as_raw_fd(), request as _, arg) };
+        if ret < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// `PERF_EVENT_IOC_ENABLE`
+    pub fn enable(&self) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_ENABLE, 0).map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_DISABLE`
+    pub fn disable(&self) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_DISABLE, 0).map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_REFRESH`: enables the event for `count` overflows.
+    pub fn refresh(&self, count: c_int) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_REFRESH, count as c_ulong)
+            .map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_RESET`: zeroes the event count.
+    pub fn reset(&self) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_RESET, 0).map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_PERIOD`: changes the sample period or frequency.
+    pub fn set_period(&self, period: u64) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_PERIOD, ptr::addr_of!(period) as c_ulong)
+            .map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_SET_OUTPUT`: redirects samples into the ring buffer
+    /// of `output`, or stops redirecting them if `None`.
+    pub fn set_output(&self, output: Option<BorrowedFd<'_>>) -> io::Result<()> {
+        let fd = output.map_or(-1, |output| output.as_raw_fd());
+        self.ioctl(PERF_EVENT_IOC_SET_OUTPUT, fd as c_ulong)
+            .map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_SET_FILTER`: sets a tracepoint filter expression.
+    pub fn set_filter(&self, filter: &str) -> io::Result<()> {
+        let filter =
+            CString::new(filter).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        self.ioctl(PERF_EVENT_IOC_SET_FILTER, filter.as_ptr() as c_ulong)
+            .map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_ID`: the event's id, as in `PERF_SAMPLE_ID`.
+    pub fn id(&self) -> io::Result<u64> {
+        let mut id = 0u64;
+        self.ioctl(PERF_EVENT_IOC_ID, ptr::addr_of_mut!(id) as c_ulong)?;
+        Ok(id)
+    }
+
+    /// `PERF_EVENT_IOC_SET_BPF`: attaches the program `prog_fd` to the
+    /// event directly, without creating a BPF link.
+    pub fn set_bpf(&self, prog_fd: BorrowedFd<'_>) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_SET_BPF, prog_fd.as_raw_fd() as c_ulong)
+            .map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_PAUSE_OUTPUT`: pauses or resumes writing to the ring
+    /// buffer.
+    pub fn pause_output(&self, pause: bool) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_PAUSE_OUTPUT, pause.into())
+            .map(drop)
+    }
+
+    /// `PERF_EVENT_IOC_MODIFY_ATTRIBUTES`: updates the attributes of a
+    /// breakpoint event.
+    pub fn modify_attributes(&self, attr: &perf_event_attr) -> io::Result<()> {
+        self.ioctl(
+            PERF_EVENT_IOC_MODIFY_ATTRIBUTES,
+            attr as *const perf_event_attr as c_ulong,
+        )
+        .map(drop)
+    }
+
+    /// Attaches `prog` with `bpf_program__attach_perf_event`. The link takes
+    /// over the event and closes it when destroyed.
+    ///
+    /// # Safety
+    ///
+    /// `prog` must be a valid, loaded program.
+    pub unsafe fn attach(self, prog: *const bpf_program) -> io::Result<*mut bpf_link> {
+        let link = bpf_program__attach_perf_event(prog, self.fd.as_raw_fd());
+        if link.is_null() {
+            // libbpf leaves the event open on failure.
+            return Err(io::Error::last_os_error());
+        }
+        let _ = self.fd.into_raw_fd();
+        Ok(link)
+    }
+}
+
+impl AsFd for PerfEvent {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl AsRawFd for PerfEvent {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+impl From<PerfEvent> for OwnedFd {
+    fn from(event: PerfEvent) -> Self {
+        event.fd
+    }
+}
@@ -0,0 +1,309 @@
+// src/perfbuf.rs
+
+//! Closure-based consumption of `BPF_MAP_TYPE_PERF_EVENT_ARRAY` buffers.
+//!
+//! [`PerfBuffer`] wraps `perf_buffer__new`, with closures for samples and
+//! lost-sample notifications, and `perf_buffer__new_raw`, with a closure
+//! that sees every `perf_event_header` record. The closures stay alive for
+//! exactly as long as the underlying `perf_buffer`.
+
+use std::io;
+use std::mem;
+use std::os::raw::c_int;
+use std::os::raw::c_void;
+use std::panic;
+use std::ptr;
+use std::slice;
+use std::time::Duration;
+
+use crate::*;
+
+/// The type of a perf ring buffer record, from `perf_event_header::type_`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PerfRecordType {
+    Mmap,
+    Lost,
+    Comm,
+    Exit,
+    Throttle,
+    Unthrottle,
+    Fork,
+    Read,
+    Sample,
+    Mmap2,
+    Aux,
+    ItraceStart,
+    LostSamples,
+    Switch,
+    SwitchCpuWide,
+    Namespaces,
+    Ksymbol,
+    BpfEvent,
+    Cgroup,
+    TextPoke,
+    AuxOutputHwId,
+    CallchainDeferred,
+}
+
+impl PerfRecordType {
+    /// Decodes a raw `PERF_RECORD_*` value.
+    pub fn from_raw(ty: u32) -> Option<Self> {
+        Some(match ty {
+            PERF_RECORD_MMAP => Self::Mmap,
+            PERF_RECORD_LOST => Self::Lost,
+            PERF_RECORD_COMM => Self::Comm,
+            PERF_RECORD_EXIT => Self::Exit,
+            PERF_RECORD_THROTTLE => Self::Throttle,
+            PERF_RECORD_UNTHROTTLE => Self::Unthrottle,
+            PERF_RECORD_FORK => Self::Fork,
+            PERF_RECORD_READ => Self::Read,
+            PERF_RECORD_SAMPLE => Self::Sample,
+            PERF_RECORD_MMAP2 => Self::Mmap2,
+            PERF_RECORD_AUX => Self::Aux,
+            PERF_RECORD_ITRACE_START => Self::ItraceStart,
+            PERF_RECORD_LOST_SAMPLES => Self::LostSamples,
+            PERF_RECORD_SWITCH => Self::Switch,
+            PERF_RECORD_SWITCH_CPU_WIDE => Self::SwitchCpuWide,
+            PERF_RECORD_NAMESPACES => Self::Namespaces,
+            PERF_RECORD_KSYMBOL => Self::Ksymbol,
+            PERF_RECORD_BPF_EVENT => Self::BpfEvent,
+            PERF_RECORD_CGROUP => Self::Cgroup,
+            PERF_RECORD_TEXT_POKE => Self::TextPoke,
+            PERF_RECORD_AUX_OUTPUT_HW_ID => Self::AuxOutputHwId,
+            PERF_RECORD_CALLCHAIN_DEFERRED => Self::CallchainDeferred,
+            _ => return None,
+        })
+    }
+
+    /// Returns the type of the record `header` starts, or `None` if it is
+    /// newer than these bindings.
+    pub fn of(header: &perf_event_header) -> Option<Self> {
+        Self::from_raw(header.type_)
+    }
+}
+
+type SampleFn<'a> = dyn FnMut(c_int, &[u8]) + 'a;
+type LostFn<'a> = dyn FnMut(c_int, u64) + 'a;
+type EventFn<'a> = dyn FnMut(c_int, &perf_event_header, &[u8]) -> bpf_perf_event_ret + 'a;
+
+enum Callbacks<'a> {
+    Sample {
+        sample: Box<SampleFn<'a>>,
+        lost: Box<LostFn<'a>>,
+    },
+    Raw {
+        event: Box<EventFn<'a>>,
+    },
+}
+
+struct Context<'a> {
+    callbacks: Callbacks<'a>,
+    panicked: bool,
+}
+
+/// Runs `f`, recording a panic in `ctx` rather than unwinding into libbpf.
+///
+/// Once a closure has panicked, records are dropped without calling it until
+/// the panic is reported. Raw event handlers also stop libbpf's loop by
+/// returning `default`; sample handlers cannot, so the rest of the batch is
+/// discarded.
+fn guard<R>(ctx: &mut Context<'_>, default: R, f: impl FnOnce(&mut Callbacks<'_>) -> R) -> R {
+    if ctx.panicked {
+        return default;
+    }
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| f(&mut ctx.callbacks))) {
+        Ok(ret) => ret,
+        Err(_) => {
+            ctx.panicked = true;
+            default
+        }
+    }
+}
+
+unsafe extern "C" fn sample_trampoline(
+    ctx: *mut c_void,
+    cpu: c_int,
+    data: *mut c_void,
+    size: __u32,
+) {
+    let data = slice::from_raw_parts(data.cast::<u8>().cast_const(), size as usize);
+    guard(&mut *ctx.cast::<Context<'_>>(), (), |callbacks| {
+        if let Callbacks::Sample { sample, .. } = callbacks {
+            sample(cpu, data);
+        }
+    });
+}
+
+unsafe extern "C" fn lost_trampoline(ctx: *mut c_void, cpu: c_int, cnt: __u64) {
+    guard(&mut *ctx.cast::<Context<'_>>(), (), |callbacks| {
+        if let Callbacks::Sample { lost, .. } = callbacks {
+            lost(cpu, cnt);
+        }
+    });
+}
+
+unsafe extern "C" fn event_trampoline(
+    ctx: *mut c_void,
+    cpu: c_int,
+    event: *mut perf_event_header,
+) -> bpf_perf_event_ret {
+    let header = &*event;
+    // libbpf hands out each record contiguously, even when it wraps around
+    // the end of the ring.
+    let body = slice::from_raw_parts(
+        event.cast::<u8>().add(mem::size_of::<perf_event_header>()),
+        (header.size as usize).saturating_sub(mem::size_of::<perf_event_header>()),
+    );
+    guard(
+        &mut *ctx.cast::<Context<'_>>(),
+        LIBBPF_PERF_EVENT_ERROR,
+        |callbacks| match callbacks {
+            Callbacks::Raw { event } => event(cpu, header, body),
+            Callbacks::Sample { .. } => LIBBPF_PERF_EVENT_CONT,
+        },
+    )
+}
+
+/// A `perf_buffer` whose records are handed to Rust closures.
+pub struct PerfBuffer<'a> {
+    pb: *mut perf_buffer,
+    ctx: Box<Context<'a>>,
+}
+
+impl<'a> PerfBuffer<'a> {
+    /// Creates a perf buffer of `page_cnt` pages per CPU, which must be a
+    /// power of two, for the `BPF_MAP_TYPE_PERF_EVENT_ARRAY` map `map_fd`.
+    ///
+    /// `sample` receives the CPU and the raw data of each `PERF_RECORD_SAMPLE`
+    /// and `lost` the CPU and count of each `PERF_RECORD_LOST`.
+    pub fn new<S, L>(map_fd: c_int, page_cnt: usize, sample: S, lost: L) -> io::Result<Self>
+    where
+        S: FnMut(c_int, &[u8]) + 'a,
+        L: FnMut(c_int, u64) + 'a,
+    {
+        let mut ctx = Box::new(Context {
+            callbacks: Callbacks::Sample {
+                sample: Box::new(sample),
+                lost: Box::new(lost),
+            },
+            panicked: false,
+        });
+        let pb = unsafe {
+            perf_buffer__new(
+                map_fd,
+                page_cnt as size_t,
+                Some(sample_trampoline),
+                Some(lost_trampoline),
+                ptr::addr_of_mut!(*ctx).cast(),
+                ptr::null(),
+            )
+        };
+        Self::from_raw(pb, ctx)
+    }
+
+    /// Creates a perf buffer opening its per-CPU events with `attr`, passing
+    /// every record to `event`.
+    ///
+    /// `event` receives the CPU, the record header and the bytes following
+    /// it, and returns `LIBBPF_PERF_EVENT_CONT` to continue,
+    /// `LIBBPF_PERF_EVENT_DONE` to stop or `LIBBPF_PERF_EVENT_ERROR` to fail.
+    pub fn new_raw<E>(
+        map_fd: c_int,
+        page_cnt: usize,
+        attr: &mut perf_event_attr,
+        event: E,
+    ) -> io::Result<Self>
+    where
+        E: FnMut(c_int, &perf_event_header, &[u8]) -> bpf_perf_event_ret + 'a,
+    {
+        let mut ctx = Box::new(Context {
+            callbacks: Callbacks::Raw {
+                event: Box::new(event),
+            },
+            panicked: false,
+        });
+        let pb = unsafe {
+            perf_buffer__new_raw(
+                map_fd,
+                page_cnt as size_t,
+                attr,
+                Some(event_trampoline),
+                ptr::addr_of_mut!(*ctx).cast(),
+                ptr::null(),
+            )
+        };
+        Self::from_raw(pb, ctx)
+    }
+
+    fn from_raw(pb: *mut perf_buffer, ctx: Box<Context<'a>>) -> io::Result<Self> {
+        if pb.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { pb, ctx })
+    }
+
+    /// Waits up to `timeout` for records, or indefinitely if `None`, and
+    /// consumes them. Returns the number of buffers that had data.
+    pub fn poll(&mut self, timeout: Option<Duration>) -> io::Result<usize> {
+        let timeout_ms = match timeout {
+            Some(timeout) => timeout.as_millis().min(c_int::MAX as u128) as c_int,
+            None => -1,
+        };
+        let ret = unsafe { perf_buffer__poll(self.pb, timeout_ms) };
+        self.result(ret)
+    }
+
+    /// Consumes all available records from every CPU without waiting.
+    pub fn consume(&mut self) -> io::Result<()> {
+        let ret = unsafe { perf_buffer__consume(self.pb) };
+        self.result(ret).map(drop)
+    }
+
+    /// Consumes all available records from buffer `buf_idx` without waiting.
+    pub fn consume_buffer(&mut self, buf_idx: usize) -> io::Result<()> {
+        let ret = unsafe { perf_buffer__consume_buffer(self.pb, buf_idx as size_t) };
+        self.result(ret).map(drop)
+    }
+
+    /// The number of per-CPU buffers.
+    pub fn buffer_cnt(&self) -> usize {
+        unsafe { perf_buffer__buffer_cnt(self.pb) as usize }
+    }
+
+    /// The perf event file descriptor of buffer `buf_idx`.
+    pub fn buffer_fd(&self, buf_idx: usize) -> io::Result<c_int> {
+        let ret = unsafe { perf_buffer__buffer_fd(self.pb, buf_idx as size_t) };
+        if ret < 0 {
+            Err(io::Error::from_raw_os_error(-ret))
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// The epoll file descriptor used by [`poll`](Self::poll), for use in
+    /// an external event loop.
+    pub fn epoll_fd(&self) -> c_int {
+        unsafe { perf_buffer__epoll_fd(self.pb) }
+    }
+
+    pub fn as_ptr(&self) -> *mut perf_buffer {
+        self.pb
+    }
+
+    fn result(&mut self, ret: c_int) -> io::Result<usize> {
+        if mem::take(&mut self.ctx.panicked) {
+            Err(io::Error::other("perf buffer callback panicked"))
+        } else if ret < 0 {
+            Err(io::Error::from_raw_os_error(-ret))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+}
+
+impl Drop for PerfBuffer<'_> {
+    fn drop(&mut self) {
+        // Frees the perf buffer before `ctx` is dropped.
+        unsafe { perf_buffer__free(self.pb) };
+    }
+}
@@ -0,0 +1,149 @@
+// src/print.rs
+
+//! Rust callbacks for libbpf's log output.
+//!
+//! `libbpf_set_print` takes a C function that receives a printf-style format
+//! string and a `va_list`, which safe Rust cannot format. [`set_print`]
+//! installs a trampoline that renders the message with `vsnprintf` and hands
+//! it to a Rust closure instead.
+
+use std::os::raw::c_char;
+use std::os::raw::c_int;
+use std::panic;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use crate::*;
+
+/// The `va_list` argument type of C callbacks such as `libbpf_print_fn_t`
+/// and `btf_dump_printf_fn_t` on the current target.
+#[cfg(not(all(target_os = "android", target_arch = "aarch64")))]
+pub type va_list_arg = *mut __va_list_tag;
+/// The `va_list` argument type of C callbacks such as `libbpf_print_fn_t`
+/// and `btf_dump_printf_fn_t` on the current target.
+#[cfg(all(target_os = "android", target_arch = "aarch64"))]
+pub type va_list_arg = va_list;
+
+/// A Rust log callback, receiving libbpf's message level and text.
+///
+/// Shared so that the trampoline can call it without holding the lock,
+/// letting the callback itself call [`set_print`].
+pub type PrintCallback = Arc<dyn Fn(libbpf_print_level, &str) + Send + Sync>;
+
+unsafe extern "C" {
+    /// `vsnprintf` on a `va_copy` of `ap`, from `src/vsnprintf.c`. `ap` is
+    /// passed through as the callback received it, so its ABI matches
+    /// whatever the target's `va_list` is.
+    fn libbpf_sys_vsnprintf(
+        buf: *mut c_char,
+        size: usize,
+        fmt: *const c_char,
+        ap: va_list_arg,
+    ) -> c_int;
+}
+
+static PRINT_CALLBACK: RwLock<Option<PrintCallback>> = RwLock::new(None);
+
+/// Formats a C format string and its `va_list` into a `String`.
+///
+/// Invalid UTF-8 is replaced with `U+FFFD`.
+///
+/// # Safety
+///
+/// `fmt` must be a valid NUL-terminated format string and `ap` a `va_list`
+/// holding matching arguments, as received by a libbpf callback. `ap` is
+/// left untouched, so it may still be passed on to other C code.
+pub unsafe fn format_va_list(fmt: *const c_char, ap: va_list_arg) -> String {
+    let mut buf = vec![0u8; 256];
+    loop {
+        let len = libbpf_sys_vsnprintf(buf.as_mut_ptr().cast(), buf.len(), fmt, ap);
+        if len < 0 {
+            return String::new();
+        }
+        let len = len as usize;
+        if len < buf.len() {
+            buf.truncate(len);
+            return String::from_utf8(buf)
+                .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned());
+        }
+        buf.resize(len + 1, 0);
+    }
+}
+
+unsafe extern "C" fn print_trampoline(
+    level: libbpf_print_level,
+    fmt: *const c_char,
+    ap: va_list_arg,
+) -> c_int {
+    let callback = match PRINT_CALLBACK.read() {
+        Ok(callback) => callback.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+    let Some(callback) = callback else {
+        return 0;
+    };
+    let msg = format_va_list(fmt, ap);
+    // Unwinding into libbpf is undefined behaviour; drop the message instead.
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| callback(level, &msg)));
+    msg.len() as c_int
+}
+
+/// Routes libbpf's log output to a Rust callback.
+///
+/// Passing `None` silences libbpf entirely, like `libbpf_set_print(NULL)`.
+/// Returns the previously installed Rust callback, if any.
+///
+/// ```no_run
+/// libbpf_sys::print::set_print(Some(std::sync::Arc::new(|level, msg| {
+///     eprint!("libbpf[{level}]: {msg}");
+/// })));
+/// ```
+pub fn set_print(callback: Option<PrintCallback>) -> Option<PrintCallback> {
+    let install = callback.is_some();
+    let prev = {
+        let mut slot = match PRINT_CALLBACK.write() {
+            Ok(slot) => slot,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        std::mem::replace(&mut *slot, callback)
+    };
+    unsafe {
+        libbpf_set_print(if install {
+            Some(print_trampoline)
+        } else {
+            None
+        });
+    }
+    prev
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn trim_newline(msg: &str) -> &str {
+    msg.strip_suffix('\n').unwrap_or(msg)
+}
+
+/// Forwards libbpf's log output to the `log` crate, with target `libbpf`.
+#[cfg(feature = "log")]
+pub fn set_print_to_log() {
+    set_print(Some(Arc::new(|level, msg| {
+        let level = match level {
+            LIBBPF_WARN => log::Level::Warn,
+            LIBBPF_INFO => log::Level::Info,
+            _ => log::Level::Debug,
+        };
+        log::log!(target: "libbpf", level, "{}", trim_newline(msg));
+    })));
+}
+
+/// Forwards libbpf's log output to the `tracing` crate, with target `libbpf`.
+#[cfg(feature = "tracing")]
+pub fn set_print_to_tracing() {
+    set_print(Some(Arc::new(|level, msg| {
+        let msg = trim_newline(msg);
+        match level {
+            LIBBPF_WARN => tracing::warn!(target: "libbpf", "{}", msg),
+            LIBBPF_INFO => tracing::info!(target: "libbpf", "{}", msg),
+            _ => tracing::debug!(target: "libbpf", "{}", msg),
+        }
+    })));
+}
@@ -0,0 +1,149 @@
+// src/prog_handler.rs
+
+//! Custom `SEC()` handlers backed by Rust closures.
+//!
+//! `libbpf_register_prog_handler` teaches libbpf new section names, calling
+//! back into the application as programs in such sections are set up,
+//! prepared for loading and auto-attached. [`ProgHandler`] registers Rust
+//! closures for those callbacks, passing them through libbpf's cookie, and
+//! unregisters them again when dropped.
+
+use std::ffi::CString;
+use std::io;
+use std::os::raw::c_int;
+use std::os::raw::c_long;
+use std::panic;
+use std::ptr;
+
+use crate::error::EINVAL;
+use crate::opts::LibbpfOpts;
+use crate::*;
+
+/// Called when libbpf sets up a program in the handled section.
+pub type ProgSetupFn = dyn Fn(*mut bpf_program) -> c_int + Send + Sync;
+/// Called right before a program in the handled section is loaded, with the
+/// options that will be passed to `bpf_prog_load`.
+pub type ProgPrepareLoadFn =
+    dyn Fn(*mut bpf_program, &mut bpf_prog_load_opts) -> c_int + Send + Sync;
+/// Called to auto-attach a program in the handled section. Returning a
+/// null link means the program was deliberately not attached.
+pub type ProgAttachFn = dyn Fn(*const bpf_program) -> Result<*mut bpf_link, c_int> + Send + Sync;
+
+/// The callbacks of a [`ProgHandler`]. Callbacks left unset fall back to
+/// libbpf's default behaviour.
+///
+/// Callbacks return 0 on success or a negative errno, as in C.
+#[derive(Default)]
+pub struct ProgHandlerCallbacks {
+    pub setup: Option<Box<ProgSetupFn>>,
+    pub prepare_load: Option<Box<ProgPrepareLoadFn>>,
+    pub attach: Option<Box<ProgAttachFn>>,
+}
+
+/// Runs a callback, turning a panic into `-EINVAL` rather than unwinding
+/// into libbpf.
+fn guard(f: impl FnOnce() -> c_int) -> c_int {
+    panic::catch_unwind(panic::AssertUnwindSafe(f)).unwrap_or(-EINVAL)
+}
+
+unsafe fn callbacks<'a>(cookie: c_long) -> &'a ProgHandlerCallbacks {
+    &*(cookie as *const ProgHandlerCallbacks)
+}
+
+unsafe extern "C" fn setup_trampoline(prog: *mut bpf_program, cookie: c_long) -> c_int {
+    let setup = callbacks(cookie).setup.as_ref();
+    guard(|| setup.map_or(0, |setup| setup(prog)))
+}
+
+unsafe extern "C" fn prepare_load_trampoline(
+    prog: *mut bpf_program,
+    opts: *mut bpf_prog_load_opts,
+    cookie: c_long,
+) -> c_int {
+    let prepare_load = callbacks(cookie).prepare_load.as_ref();
+    guard(|| prepare_load.map_or(0, |prepare_load| prepare_load(prog, &mut *opts)))
+}
+
+unsafe extern "C" fn attach_trampoline(
+    prog: *const bpf_program,
+    cookie: c_long,
+    link: *mut *mut bpf_link,
+) -> c_int {
+    let attach = callbacks(cookie).attach.as_ref();
+    guard(|| match attach.map(|attach| attach(prog)) {
+        Some(Ok(new_link)) => {
+            *link = new_link;
+            0
+        }
+        Some(Err(err)) => err,
+        None => 0,
+    })
+}
+
+/// A registered custom section handler, unregistered on drop.
+pub struct ProgHandler {
+    id: c_int,
+    _callbacks: Box<ProgHandlerCallbacks>,
+}
+
+impl ProgHandler {
+    /// Registers `callbacks` for programs in section `sec`, which get
+    /// program type `prog_type` and expected attach type `exp_attach_type`.
+    ///
+    /// As in libbpf, a `sec` ending in `+` also matches `sec/...`
+    /// subsections, and `None` registers a fallback for any section no other
+    /// handler recognizes.
+    ///
+    /// libbpf's handler registry is not thread-safe: avoid registering or
+    /// dropping handlers while other threads open or load objects. Before
+    /// libbpf 1.2, dropping the last handler also leaves the registry
+    /// dangling, corrupting the heap when another is registered later.
+    pub fn register(
+        sec: Option<&str>,
+        prog_type: bpf_prog_type,
+        exp_attach_type: bpf_attach_type,
+        callbacks: ProgHandlerCallbacks,
+    ) -> io::Result<Self> {
+        let sec = sec
+            .map(CString::new)
+            .transpose()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        let callbacks = Box::new(callbacks);
+        let mut opts = libbpf_prog_handler_opts::new_sized();
+        opts.cookie = ptr::addr_of!(*callbacks) as c_long;
+        opts.prog_setup_fn = callbacks.setup.as_ref().and(Some(setup_trampoline));
+        opts.prog_prepare_load_fn = callbacks
+            .prepare_load
+            .as_ref()
+            .and(Some(prepare_load_trampoline));
+        opts.prog_attach_fn = callbacks.attach.as_ref().and(Some(attach_trampoline));
+
+        let id = unsafe {
+            libbpf_register_prog_handler(
+                sec.as_ref().map_or(ptr::null(), |sec| sec.as_ptr()),
+                prog_type,
+                exp_attach_type,
+                &opts,
+            )
+        };
+        if id < 0 {
+            return Err(io::Error::from_raw_os_error(-id));
+        }
+        Ok(Self {
+            id,
+            _callbacks: callbacks,
+        })
+    }
+
+    /// The handler id returned by `libbpf_register_prog_handler`.
+    pub fn id(&self) -> c_int {
+        self.id
+    }
+}
+
+impl Drop for ProgHandler {
+    fn drop(&mut self) {
+        // Unregisters the handler before the callbacks are dropped.
+        unsafe { libbpf_unregister_prog_handler(self.id) };
+    }
+}
@@ -0,0 +1,148 @@
+// src/ringbuf.rs
+
+//! Closure-based consumption of BPF ring buffers.
+//!
+//! `ring_buffer__new` and `ring_buffer__add` take a C callback and a `void *`
+//! context. [`RingBuffer`] registers Rust closures instead and keeps them
+//! alive for exactly as long as the underlying `ring_buffer`.
+
+use std::io;
+use std::os::raw::c_int;
+use std::os::raw::c_void;
+use std::panic;
+use std::ptr;
+use std::slice;
+use std::time::Duration;
+
+use crate::*;
+
+type SampleFn<'a> = dyn FnMut(&[u8]) -> i32 + 'a;
+
+struct Callback<'a> {
+    sample: Box<SampleFn<'a>>,
+    panicked: bool,
+}
+
+unsafe extern "C" fn sample_trampoline(ctx: *mut c_void, data: *mut c_void, size: size_t) -> c_int {
+    let callback = &mut *ctx.cast::<Callback<'_>>();
+    // `size_t` is `usize` already with the `size-t-is-usize` feature.
+    #[allow(clippy::unnecessary_cast)]
+    let data = slice::from_raw_parts(data.cast::<u8>().cast_const(), size as usize);
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| (callback.sample)(data))) {
+        Ok(ret) => ret,
+        Err(_) => {
+            // Unwinding into libbpf is undefined behaviour. Any negative
+            // value stops consumption; the caller reports the panic.
+            callback.panicked = true;
+            -1
+        }
+    }
+}
+
+/// A `ring_buffer` whose samples are handed to Rust closures.
+///
+/// Each closure receives one record and returns 0 to continue, or a
+/// negative error to stop consumption, like `ring_buffer_sample_fn`.
+pub struct RingBuffer<'a> {
+    rb: *mut ring_buffer,
+    // Boxed so that the context pointers held by libbpf stay valid when the
+    // vector grows.
+    #[allow(clippy::vec_box)]
+    callbacks: Vec<Box<Callback<'a>>>,
+}
+
+impl<'a> RingBuffer<'a> {
+    /// Creates a ring buffer manager consuming the `BPF_MAP_TYPE_RINGBUF`
+    /// map `map_fd` with `sample`.
+    pub fn new<F: FnMut(&[u8]) -> i32 + 'a>(map_fd: c_int, sample: F) -> io::Result<Self> {
+        let mut callback = Box::new(Callback {
+            sample: Box::new(sample),
+            panicked: false,
+        });
+        let rb = unsafe {
+            ring_buffer__new(
+                map_fd,
+                Some(sample_trampoline),
+                ptr::addr_of_mut!(*callback).cast(),
+                ptr::null(),
+            )
+        };
+        if rb.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            rb,
+            callbacks: vec![callback],
+        })
+    }
+
+    /// Adds another ring buffer map, consumed with its own closure.
+    pub fn add<F: FnMut(&[u8]) -> i32 + 'a>(&mut self, map_fd: c_int, sample: F) -> io::Result<()> {
+        let mut callback = Box::new(Callback {
+            sample: Box::new(sample),
+            panicked: false,
+        });
+        let ret = unsafe {
+            ring_buffer__add(
+                self.rb,
+                map_fd,
+                Some(sample_trampoline),
+                ptr::addr_of_mut!(*callback).cast(),
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::from_raw_os_error(-ret));
+        }
+        self.callbacks.push(callback);
+        Ok(())
+    }
+
+    /// Waits up to `timeout` for records, or indefinitely if `None`, and
+    /// consumes them. Returns the number of records consumed.
+    pub fn poll(&mut self, timeout: Option<Duration>) -> io::Result<usize> {
+        let timeout_ms = match timeout {
+            Some(timeout) => timeout.as_millis().min(c_int::MAX as u128) as c_int,
+            None => -1,
+        };
+        let ret = unsafe { ring_buffer__poll(self.rb, timeout_ms) };
+        self.result(ret)
+    }
+
+    /// Consumes all available records without waiting. Returns the number
+    /// of records consumed.
+    pub fn consume(&mut self) -> io::Result<usize> {
+        let ret = unsafe { ring_buffer__consume(self.rb) };
+        self.result(ret)
+    }
+
+    /// The epoll file descriptor used by [`poll`](Self::poll), for use in
+    /// an external event loop.
+    pub fn epoll_fd(&self) -> c_int {
+        unsafe { ring_buffer__epoll_fd(self.rb) }
+    }
+
+    pub fn as_ptr(&self) -> *mut ring_buffer {
+        self.rb
+    }
+
+    fn result(&mut self, ret: c_int) -> io::Result<usize> {
+        let mut panicked = false;
+        for callback in &mut self.callbacks {
+            panicked |= std::mem::take(&mut callback.panicked);
+        }
+        if panicked {
+            Err(io::Error::other("ring buffer callback panicked"))
+        } else if ret < 0 {
+            Err(io::Error::from_raw_os_error(-ret))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+}
+
+impl Drop for RingBuffer<'_> {
+    fn drop(&mut self) {
+        // Frees the ring buffer before `callbacks` are dropped.
+        unsafe { ring_buffer__free(self.rb) };
+    }
+}
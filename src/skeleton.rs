@@ -0,0 +1,248 @@
+// src/skeleton.rs
+
+//! Building `bpf_object_skeleton`s at runtime.
+//!
+//! `bpftool gen skeleton` emits C that fills in a `bpf_object_skeleton` with
+//! arrays of `bpf_map_skeleton` and `bpf_prog_skeleton`, each pointing at
+//! slots that libbpf fills in as the object is opened and loaded.
+//! [`ObjectSkeletonBuilder`] assembles the same structure from an embedded
+//! ELF image and lists of map and program names, and owns all of its
+//! storage.
+//!
+//! ```no_run
+//! use libbpf_sys::skeleton::ObjectSkeletonBuilder;
+//!
+//! static OBJ: &[u8] = &[/* include_bytes!("prog.bpf.o") */];
+//!
+//! let mut skel = ObjectSkeletonBuilder::new("prog", OBJ)
+//!     .map("counts", false)
+//!     .map(".bss", true)
+//!     .prog("handle_exec")
+//!     .build()
+//!     .unwrap();
+//! skel.open(None).unwrap();
+//! skel.load().unwrap();
+//! let bss = skel.mmaped(".bss").unwrap();
+//! skel.attach().unwrap();
+//! ```
+
+use std::ffi::CString;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::c_int;
+use std::os::raw::c_void;
+use std::ptr;
+
+use crate::*;
+
+/// Collects the contents of an [`ObjectSkeleton`].
+pub struct ObjectSkeletonBuilder<'a> {
+    name: &'a str,
+    data: &'a [u8],
+    maps: Vec<(&'a str, bool)>,
+    progs: Vec<&'a str>,
+}
+
+impl<'a> ObjectSkeletonBuilder<'a> {
+    /// Starts a skeleton for the object `name`, whose ELF image is `data`.
+    pub fn new(name: &'a str, data: &'a [u8]) -> Self {
+        Self {
+            name,
+            data,
+            maps: Vec::new(),
+            progs: Vec::new(),
+        }
+    }
+
+    /// Adds the map `name`. With `mmaped`, the map's memory-mapped contents
+    /// are exposed after loading; this only applies to global data maps such
+    /// as `.bss`, `.data` and `.rodata`, and to `BPF_F_MMAPABLE` arrays.
+    pub fn map(mut self, name: &'a str, mmaped: bool) -> Self {
+        self.maps.push((name, mmaped));
+        self
+    }
+
+    /// Adds the program `name`, the name of its C function.
+    pub fn prog(mut self, name: &'a str) -> Self {
+        self.progs.push(name);
+        self
+    }
+
+    pub fn build(self) -> io::Result<ObjectSkeleton<'a>> {
+        let cstring = |name: &str| {
+            CString::new(name).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+        };
+        let name = cstring(self.name)?;
+        let map_names = self
+            .maps
+            .iter()
+            .map(|(name, _)| cstring(name))
+            .collect::<io::Result<Vec<_>>>()?;
+        let prog_names = self
+            .progs
+            .iter()
+            .map(|name| cstring(name))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        fn slots<T>(n: usize) -> Box<[*mut T]> {
+            vec![ptr::null_mut(); n].into_boxed_slice()
+        }
+        let mut skel = ObjectSkeleton {
+            skel: Box::new(unsafe { mem::zeroed() }),
+            obj: Box::new(ptr::null_mut()),
+            maps: Vec::with_capacity(map_names.len()).into_boxed_slice(),
+            progs: Vec::with_capacity(prog_names.len()).into_boxed_slice(),
+            map_ptrs: slots(map_names.len()),
+            mmaped: slots(map_names.len()),
+            map_links: slots(map_names.len()),
+            prog_ptrs: slots(prog_names.len()),
+            prog_links: slots(prog_names.len()),
+            name,
+            map_names,
+            prog_names,
+            _data: PhantomData,
+        };
+
+        skel.maps = (0..skel.map_names.len())
+            .map(|i| bpf_map_skeleton {
+                name: skel.map_names[i].as_ptr(),
+                map: &mut skel.map_ptrs[i],
+                mmaped: if self.maps[i].1 {
+                    &mut skel.mmaped[i]
+                } else {
+                    ptr::null_mut()
+                },
+                link: &mut skel.map_links[i],
+            })
+            .collect();
+        skel.progs = (0..skel.prog_names.len())
+            .map(|i| bpf_prog_skeleton {
+                name: skel.prog_names[i].as_ptr(),
+                prog: &mut skel.prog_ptrs[i],
+                link: &mut skel.prog_links[i],
+            })
+            .collect();
+
+        *skel.skel = bpf_object_skeleton {
+            sz: mem::size_of::<bpf_object_skeleton>() as size_t,
+            name: skel.name.as_ptr(),
+            data: self.data.as_ptr().cast(),
+            data_sz: self.data.len() as size_t,
+            obj: &mut *skel.obj,
+            map_cnt: skel.maps.len() as c_int,
+            map_skel_sz: mem::size_of::<bpf_map_skeleton>() as c_int,
+            maps: skel.maps.as_mut_ptr(),
+            prog_cnt: skel.progs.len() as c_int,
+            prog_skel_sz: mem::size_of::<bpf_prog_skeleton>() as c_int,
+            progs: skel.progs.as_mut_ptr(),
+        };
+        Ok(skel)
+    }
+}
+
+/// A `bpf_object_skeleton` along with all the storage it points into.
+///
+/// Dropping it detaches any links and closes the object.
+pub struct ObjectSkeleton<'a> {
+    skel: Box<bpf_object_skeleton>,
+    obj: Box<*mut bpf_object>,
+    maps: Box<[bpf_map_skeleton]>,
+    progs: Box<[bpf_prog_skeleton]>,
+    map_ptrs: Box<[*mut bpf_map]>,
+    mmaped: Box<[*mut c_void]>,
+    map_links: Box<[*mut bpf_link]>,
+    prog_ptrs: Box<[*mut bpf_program]>,
+    prog_links: Box<[*mut bpf_link]>,
+    name: CString,
+    map_names: Vec<CString>,
+    prog_names: Vec<CString>,
+    // libbpf reads the ELF image until the object is loaded.
+    _data: PhantomData<&'a [u8]>,
+}
+
+fn position(names: &[CString], name: &str) -> Option<usize> {
+    names.iter().position(|n| n.to_bytes() == name.as_bytes())
+}
+
+fn result(ret: c_int) -> io::Result<()> {
+    if ret < 0 {
+        Err(io::Error::from_raw_os_error(-ret))
+    } else {
+        Ok(())
+    }
+}
+
+impl ObjectSkeleton<'_> {
+    /// Opens the object with `bpf_object__open_skeleton`, filling in the map
+    /// and program pointers.
+    pub fn open(&mut self, opts: Option<&bpf_object_open_opts>) -> io::Result<()> {
+        let opts = opts.map_or(ptr::null(), |opts| opts as *const _);
+        result(unsafe { bpf_object__open_skeleton(&mut *self.skel, opts) })
+    }
+
+    /// Loads the object with `bpf_object__load_skeleton`, remapping global
+    /// data onto the created maps.
+    pub fn load(&mut self) -> io::Result<()> {
+        result(unsafe { bpf_object__load_skeleton(&mut *self.skel) })
+    }
+
+    /// Auto-attaches all programs with `bpf_object__attach_skeleton`.
+    pub fn attach(&mut self) -> io::Result<()> {
+        result(unsafe { bpf_object__attach_skeleton(&mut *self.skel) })
+    }
+
+    /// Destroys all links created by [`attach`](Self::attach).
+    pub fn detach(&mut self) {
+        unsafe { bpf_object__detach_skeleton(&mut *self.skel) }
+    }
+
+    /// The object, or null before [`open`](Self::open).
+    pub fn object(&self) -> *mut bpf_object {
+        *self.obj
+    }
+
+    /// The map added as `name`, once opened.
+    pub fn map(&self, name: &str) -> Option<*mut bpf_map> {
+        let idx = position(&self.map_names, name)?;
+        Some(self.map_ptrs[idx]).filter(|map| !map.is_null())
+    }
+
+    /// The memory-mapped contents of the map added as `name` with `mmaped`.
+    ///
+    /// After [`open`](Self::open) this holds the initial contents, which may
+    /// be changed before loading; after [`load`](Self::load) it maps the
+    /// kernel map itself (read-only for `.rodata`), or is `None` if the map
+    /// could not be memory-mapped.
+    pub fn mmaped(&self, name: &str) -> Option<*mut c_void> {
+        let idx = position(&self.map_names, name)?;
+        Some(self.mmaped[idx]).filter(|mmaped| !mmaped.is_null())
+    }
+
+    /// The program added as `name`, once opened.
+    pub fn prog(&self, name: &str) -> Option<*mut bpf_program> {
+        let idx = position(&self.prog_names, name)?;
+        Some(self.prog_ptrs[idx]).filter(|prog| !prog.is_null())
+    }
+
+    /// The link of the program added as `name`, once attached.
+    pub fn link(&self, name: &str) -> Option<*mut bpf_link> {
+        let idx = position(&self.prog_names, name)?;
+        Some(self.prog_links[idx]).filter(|link| !link.is_null())
+    }
+
+    pub fn as_ptr(&self) -> *const bpf_object_skeleton {
+        &*self.skel
+    }
+}
+
+impl Drop for ObjectSkeleton<'_> {
+    fn drop(&mut self) {
+        // Not `bpf_object__destroy_skeleton`, which would `free()` storage
+        // that Rust owns.
+        unsafe {
+            bpf_object__detach_skeleton(&mut *self.skel);
+            bpf_object__close(*self.obj);
+        }
+    }
+}
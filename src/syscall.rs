@@ -0,0 +1,106 @@
+// src/syscall.rs
+
+//! Direct `bpf(2)` syscalls, bypassing libbpf.
+//!
+//! Useful for commands that `bpf.h` does not wrap yet, or for small binaries
+//! that would rather not link libbpf at all: nothing in this module calls
+//! into the native library.
+//!
+//! Commands take one of the per-command members of `bpf_attr`, such as
+//! [`bpf_attr_map_create`]. It is copied into a
+//! zeroed `bpf_attr` and passed with its own size, as libbpf does with
+//! `offsetofend()`, so that kernels predating newer fields still accept it.
+//! Whatever the kernel writes back into the attr is copied back out.
+//!
+//! ```no_run
+//! use libbpf_sys::syscall::sys_bpf_fd;
+//! use libbpf_sys::*;
+//!
+//! let mut attr = bpf_attr_map_create {
+//!     map_type: BPF_MAP_TYPE_ARRAY,
+//!     key_size: 4,
+//!     value_size: 8,
+//!     max_entries: 1,
+//!     ..Default::default()
+//! };
+//! let map = unsafe { sys_bpf_fd(BPF_MAP_CREATE, &mut attr) }.unwrap();
+//! ```
+
+use std::io;
+use std::mem;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::raw::c_int;
+use std::os::raw::c_long;
+use std::ptr;
+
+use crate::*;
+
+/// How often `BPF_PROG_LOAD` is retried on `EAGAIN`, as in libbpf.
+const PROG_LOAD_ATTEMPTS: usize = 5;
+
+/// Issues `bpf(cmd, attr, size)`.
+///
+/// # Safety
+///
+/// `attr` must hold a valid attr for `cmd` in its first `size` bytes, and
+/// every pointer in it must be valid for the accesses `cmd` makes.
+pub unsafe fn sys_bpf_raw(cmd: bpf_cmd, attr: *mut bpf_attr, size: u32) -> io::Result<c_int> {
+    let ret = libc::syscall(libc::SYS_bpf, cmd as c_long, attr, size as c_long);
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret as c_int)
+    }
+}
+
+/// Issues `cmd` with the per-command attr `attr`, returning the syscall's
+/// non-negative result.
+///
+/// # Safety
+///
+/// `A` must be the `bpf_attr` member for `cmd`, and every pointer in it
+/// must be valid for the accesses `cmd` makes.
+///
+/// # Panics
+///
+/// If `A` is larger than `bpf_attr`.
+pub unsafe fn sys_bpf<A: Copy>(cmd: bpf_cmd, attr: &mut A) -> io::Result<c_int> {
+    let size = mem::size_of::<A>();
+    assert!(
+        size <= mem::size_of::<bpf_attr>(),
+        "attr does not fit in bpf_attr"
+    );
+    let mut full = bpf_attr::default();
+    let bytes = ptr::addr_of_mut!(full).cast::<u8>();
+    ptr::copy_nonoverlapping((attr as *const A).cast::<u8>(), bytes, size);
+    let ret = sys_bpf_raw(cmd, &mut full, size as u32);
+    ptr::copy_nonoverlapping(bytes, (attr as *mut A).cast::<u8>(), size);
+    ret
+}
+
+/// Issues a command that returns a new file descriptor, such as
+/// `BPF_MAP_CREATE`, `BPF_PROG_LOAD`, `BPF_LINK_CREATE` or one of the
+/// `*_GET_FD_BY_ID` commands.
+///
+/// `BPF_PROG_LOAD` is retried on `EAGAIN`, which the verifier returns when
+/// interrupted.
+///
+/// # Safety
+///
+/// See [`sys_bpf`]. `cmd` must return a file descriptor on success.
+pub unsafe fn sys_bpf_fd<A: Copy>(cmd: bpf_cmd, attr: &mut A) -> io::Result<OwnedFd> {
+    let attempts = if cmd == BPF_PROG_LOAD {
+        PROG_LOAD_ATTEMPTS
+    } else {
+        1
+    };
+    let mut ret = sys_bpf(cmd, attr);
+    for _ in 1..attempts {
+        match &ret {
+            Err(err) if err.raw_os_error() == Some(libc::EAGAIN) => ret = sys_bpf(cmd, attr),
+            _ => break,
+        }
+    }
+    ret.map(|fd| OwnedFd::from_raw_fd(fd))
+}
@@ -0,0 +1,161 @@
+// src/type_names.rs
+
+//! Named wrappers for the program, map, attach and link type enums.
+//!
+//! The bindings represent `enum bpf_prog_type` and friends as bare integers.
+//! [`ProgType`], [`MapType`], [`AttachType`] and [`LinkType`] wrap them
+//! with `Display` and `FromStr` based on libbpf's own names, such as
+//! `"socket_filter"` or `"cgroup_inet_ingress"`, and can iterate over every
+//! value known to the bindings.
+//!
+//! Names come from the linked libbpf, which may be older than the bindings.
+//! Values it has no name for display as their number, which also parses
+//! back.
+//!
+//! ```
+//! use libbpf_sys::type_names::MapType;
+//! use libbpf_sys::*;
+//!
+//! let ty: MapType = "hash".parse().unwrap();
+//! assert_eq!(ty, MapType(BPF_MAP_TYPE_HASH));
+//! assert_eq!(ty.to_string(), "hash");
+//! ```
+
+use std::error::Error;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::*;
+
+/// The error returned when parsing an unknown type name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTypeError {
+    kind: &'static str,
+    name: String,
+}
+
+impl fmt::Display for ParseTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown BPF {} type {:?}", self.kind, self.name)
+    }
+}
+
+impl Error for ParseTypeError {}
+
+macro_rules! type_name {
+    ($(#[$attr:meta])* $name:ident($raw:ty), $kind:literal, $str_fn:ident, $max:ident) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub $raw);
+
+        impl $name {
+            /// Iterates over every value in the bindings, in numeric order.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                (0..$max).map(Self)
+            }
+
+            /// The linked libbpf's name for this value, or `None` if it
+            /// does not know it.
+            pub fn name(&self) -> Option<&'static str> {
+                let name = unsafe { $str_fn(self.0) };
+                if name.is_null() {
+                    return None;
+                }
+                unsafe { CStr::from_ptr(name) }.to_str().ok()
+            }
+        }
+
+        impl From<$raw> for $name {
+            fn from(raw: $raw) -> Self {
+                Self(raw)
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(ty: $name) -> Self {
+                ty.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.name() {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "{}", self.0),
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseTypeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if let Ok(raw) = s.parse() {
+                    return Ok(Self(raw));
+                }
+                Self::iter()
+                    .find(|ty| ty.name() == Some(s))
+                    .ok_or_else(|| ParseTypeError {
+                        kind: $kind,
+                        name: s.to_owned(),
+                    })
+            }
+        }
+    };
+}
+
+type_name!(
+    /// A `bpf_prog_type`, named as in `libbpf_bpf_prog_type_str`.
+    ProgType(bpf_prog_type),
+    "program",
+    libbpf_bpf_prog_type_str,
+    __MAX_BPF_PROG_TYPE
+);
+type_name!(
+    /// A `bpf_map_type`, named as in `libbpf_bpf_map_type_str`.
+    MapType(bpf_map_type),
+    "map",
+    libbpf_bpf_map_type_str,
+    __MAX_BPF_MAP_TYPE
+);
+type_name!(
+    /// A `bpf_attach_type`, named as in `libbpf_bpf_attach_type_str`.
+    AttachType(bpf_attach_type),
+    "attach",
+    libbpf_bpf_attach_type_str,
+    __MAX_BPF_ATTACH_TYPE
+);
+type_name!(
+    /// A `bpf_link_type`, named as in `libbpf_bpf_link_type_str`.
+    LinkType(bpf_link_type),
+    "link",
+    libbpf_bpf_link_type_str,
+    __MAX_BPF_LINK_TYPE
+);
+
+impl ProgType {
+    /// Looks up the program type and expected attach type libbpf assigns
+    /// to programs in the `SEC()` named `sec`, such as `"kprobe/do_exit"`.
+    pub fn from_section(sec: &str) -> Option<(Self, AttachType)> {
+        let sec = CString::new(sec).ok()?;
+        let mut prog_type = 0;
+        let mut attach_type = 0;
+        let ret =
+            unsafe { libbpf_prog_type_by_name(sec.as_ptr(), &mut prog_type, &mut attach_type) };
+        (ret == 0).then_some((Self(prog_type), AttachType(attach_type)))
+    }
+}
+
+impl AttachType {
+    /// Looks up the attach type of the `SEC()` named `sec`, if libbpf
+    /// allows attaching programs from it with `bpf_prog_attach`.
+    pub fn from_section(sec: &str) -> Option<Self> {
+        let sec = CString::new(sec).ok()?;
+        let mut attach_type = 0;
+        let ret = unsafe { libbpf_attach_type_by_name(sec.as_ptr(), &mut attach_type) };
+        (ret == 0).then_some(Self(attach_type))
+    }
+}
@@ -0,0 +1,454 @@
+// src/verifier_log.rs
+
+//! Capturing and parsing the verifier log.
+//!
+//! [`prog_load_with_log`] and [`btf_load_with_log`] wrap `bpf_prog_load` and
+//! `bpf_btf_load` with a log buffer that grows until the whole log fits.
+//! [`VerifierLog::parse`] turns the text into [`VerifierLogLine`] records:
+//! instructions with the register states printed alongside them, source
+//! line annotations, the `processed N insns` statistics and, on rejection,
+//! the error.
+//!
+//! ```no_run
+//! use libbpf_sys::insn::BPF_EXIT_INSN;
+//! use libbpf_sys::opts::LibbpfOpts;
+//! use libbpf_sys::verifier_log::*;
+//! use libbpf_sys::*;
+//!
+//! let insns = [BPF_EXIT_INSN()];
+//! let mut opts = bpf_prog_load_opts::new_sized();
+//! let (fd, log) =
+//!     prog_load_with_log(BPF_PROG_TYPE_SOCKET_FILTER, None, "GPL", &insns, &mut opts, 1);
+//! if fd.is_err() {
+//!     let log = VerifierLog::parse(&log);
+//!     eprintln!("rejected: {}", log.error().unwrap_or("unknown error"));
+//! }
+//! ```
+
+use std::ffi::CString;
+use std::io;
+use std::mem;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::raw::c_char;
+use std::os::raw::c_int;
+
+use crate::error::ENOSPC;
+use crate::*;
+
+/// The size of the first log buffer tried.
+const INITIAL_LOG_SIZE: usize = 64 * 1024;
+
+/// The largest log buffer the kernel accepts.
+const MAX_LOG_SIZE: usize = (u32::MAX >> 2) as usize;
+
+/// Calls `load` with successively larger log buffers for as long as it
+/// fails with `ENOSPC`, returning its result and the log of the last call.
+///
+/// `load` returns an fd or a negative errno, and the size the kernel needed
+/// if it reported one.
+fn with_growing_log(
+    mut load: impl FnMut(*mut c_char, u32) -> (c_int, u32),
+) -> (io::Result<OwnedFd>, String) {
+    let mut buf = vec![0u8; INITIAL_LOG_SIZE];
+    loop {
+        buf[0] = 0;
+        let (ret, true_size) = load(buf.as_mut_ptr().cast(), buf.len() as u32);
+        if ret == -ENOSPC && buf.len() < MAX_LOG_SIZE {
+            let size = (buf.len() * 2).max(true_size as usize).min(MAX_LOG_SIZE);
+            buf.resize(size, 0);
+            continue;
+        }
+
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        let log = String::from_utf8_lossy(&buf[..len]).into_owned();
+        let fd = if ret < 0 {
+            Err(io::Error::from_raw_os_error(-ret))
+        } else {
+            Ok(unsafe { OwnedFd::from_raw_fd(ret) })
+        };
+        return (fd, log);
+    }
+}
+
+fn cstring(s: &str) -> io::Result<CString> {
+    CString::new(s).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+/// Loads a program with `bpf_prog_load`, capturing the verifier log at
+/// `log_level` (`1`, or `2` for the state at every instruction, optionally
+/// with `BPF_LOG_STATS`).
+///
+/// The log buffer starts at 64 KiB and doubles whenever the kernel fails
+/// the load with `ENOSPC`, up to the kernel's limit. `opts` is passed
+/// through with its log fields replaced, and has them cleared on return.
+/// The log is returned whether or not the load succeeded.
+///
+/// libbpf only exposes fixed-size buffers for programs loaded as part of
+/// an object, since an object cannot be loaded twice; use
+/// `bpf_program__set_log_buf` with a generous buffer for those and parse
+/// what it captured.
+pub fn prog_load_with_log(
+    prog_type: bpf_prog_type,
+    prog_name: Option<&str>,
+    license: &str,
+    insns: &[bpf_insn],
+    opts: &mut bpf_prog_load_opts,
+    log_level: u32,
+) -> (io::Result<OwnedFd>, String) {
+    let (prog_name, license) = match (prog_name.map(cstring).transpose(), cstring(license)) {
+        (Ok(prog_name), Ok(license)) => (prog_name, license),
+        (Err(e), _) | (_, Err(e)) => return (Err(e), String::new()),
+    };
+    let result = with_growing_log(|buf, size| {
+        opts.log_buf = buf;
+        opts.log_size = size;
+        opts.log_level = log_level;
+        opts.log_true_size = 0;
+        let ret = unsafe {
+            bpf_prog_load(
+                prog_type,
+                prog_name
+                    .as_ref()
+                    .map_or(std::ptr::null(), |name| name.as_ptr()),
+                license.as_ptr(),
+                insns.as_ptr(),
+                insns.len() as _,
+                opts,
+            )
+        };
+        (ret, opts.log_true_size)
+    });
+    opts.log_buf = std::ptr::null_mut();
+    opts.log_size = 0;
+    result
+}
+
+/// Loads raw BTF with `bpf_btf_load`, capturing the kernel's BTF
+/// verification log at `log_level`, growing the buffer like
+/// [`prog_load_with_log`].
+pub fn btf_load_with_log(
+    data: &[u8],
+    opts: &mut bpf_btf_load_opts,
+    log_level: u32,
+) -> (io::Result<OwnedFd>, String) {
+    let result = with_growing_log(|buf, size| {
+        opts.log_buf = buf;
+        opts.log_size = size;
+        opts.log_level = log_level;
+        opts.log_true_size = 0;
+        let ret = unsafe { bpf_btf_load(data.as_ptr().cast(), data.len() as _, opts) };
+        (ret, opts.log_true_size)
+    });
+    opts.log_buf = std::ptr::null_mut();
+    opts.log_size = 0;
+    result
+}
+
+/// A register or stack slot in a verifier state, such as `R1=ctx()` or
+/// `fp-8_w=mmmmmmmm`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegState {
+    /// The register or slot, such as `R1` or `fp-8`.
+    pub name: String,
+    /// Whether the liveness marker `_w` was present, meaning the value was
+    /// written in the current state. Recent kernels no longer print it.
+    pub written: bool,
+    /// The verifier's description of the value, such as `ctx()` or
+    /// `scalar(umax=255,var_off=(0x0; 0xff))`.
+    pub value: String,
+}
+
+/// The statistics the verifier prints at the end of every log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifierStats {
+    /// Instructions processed, across all explored paths.
+    pub processed_insns: u64,
+    /// The complexity limit `processed_insns` is checked against.
+    pub insn_limit: u64,
+    pub max_states_per_insn: u64,
+    pub total_states: u64,
+    pub peak_states: u64,
+    pub mark_read: u64,
+    /// From the `verification time N usec` line printed with
+    /// `BPF_LOG_STATS`, or zero without it.
+    pub verification_time_usec: u64,
+    /// From the `stack depth N+M` line printed with `BPF_LOG_STATS`: the
+    /// stack depth of each subprogram, or empty without it.
+    pub stack_depths: Vec<u32>,
+}
+
+/// One line of a verifier log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifierLogLine {
+    /// An instruction, such as `0: (b7) r0 = 0`, with the state after it
+    /// if printed on the same line (log level 2).
+    Insn {
+        idx: u32,
+        /// The opcode byte.
+        code: u8,
+        text: String,
+        state: Vec<RegState>,
+    },
+    /// The state on reaching an instruction, such as `0: R1=ctx() R10=fp0`.
+    State {
+        idx: u32,
+        /// The call frame, for states printed as `N: frameF: ...`.
+        frame: Option<u32>,
+        state: Vec<RegState>,
+    },
+    /// The state on taking a branch, such as `from 3 to 5: R0=0 R10=fp0`.
+    Branch {
+        from: u32,
+        to: u32,
+        state: Vec<RegState>,
+    },
+    /// A source line annotation from BTF line info, such as
+    /// `; return 0; @ prog.bpf.c:12`. Older kernels omit the location.
+    Source {
+        text: String,
+        file: Option<String>,
+        line: Option<u32>,
+    },
+    /// The `processed N insns ...` statistics, merged with the
+    /// `verification time` and `stack depth` lines preceding them.
+    Stats(VerifierStats),
+    /// Any other line, including errors.
+    Other(String),
+}
+
+/// A parsed verifier log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifierLog {
+    pub lines: Vec<VerifierLogLine>,
+}
+
+impl VerifierLog {
+    /// Parses a verifier log. Lines not recognised are kept as
+    /// [`VerifierLogLine::Other`]; parsing never fails.
+    pub fn parse(log: &str) -> Self {
+        let mut lines = Vec::new();
+        // `BPF_LOG_STATS` adds two lines right before `processed N insns`.
+        let mut extra = VerifierStats::default();
+        for line in log.lines().filter(|line| !line.trim().is_empty()) {
+            if let Some(usec) = parse_verification_time(line) {
+                extra.verification_time_usec = usec;
+                continue;
+            }
+            if let Some(depths) = parse_stack_depth(line) {
+                extra.stack_depths = depths;
+                continue;
+            }
+            lines.push(match parse_line(line) {
+                VerifierLogLine::Stats(stats) => VerifierLogLine::Stats(VerifierStats {
+                    verification_time_usec: extra.verification_time_usec,
+                    stack_depths: mem::take(&mut extra.stack_depths),
+                    ..stats
+                }),
+                line => line,
+            });
+        }
+        Self { lines }
+    }
+
+    /// The error line of a rejected program: the last unrecognised line
+    /// before the statistics, such as `R0 !read_ok`.
+    ///
+    /// The log of an accepted program has no error, but this may still
+    /// return an informational line from it; only consult it if the load
+    /// failed.
+    pub fn error(&self) -> Option<&str> {
+        let end = self
+            .lines
+            .iter()
+            .position(|line| matches!(line, VerifierLogLine::Stats(_)))
+            .unwrap_or(self.lines.len());
+        self.lines[..end].iter().rev().find_map(|line| match line {
+            VerifierLogLine::Other(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The `processed N insns` statistics, if the log got that far.
+    pub fn stats(&self) -> Option<&VerifierStats> {
+        self.lines.iter().find_map(|line| match line {
+            VerifierLogLine::Stats(stats) => Some(stats),
+            _ => None,
+        })
+    }
+
+    /// The last instruction the verifier reached, which for a rejected
+    /// program is usually the one it rejected.
+    pub fn last_insn(&self) -> Option<u32> {
+        self.lines.iter().rev().find_map(|line| match line {
+            VerifierLogLine::Insn { idx, .. } => Some(*idx),
+            _ => None,
+        })
+    }
+
+    /// The source annotation closest before instruction `idx`'s last
+    /// appearance.
+    pub fn source_for(&self, idx: u32) -> Option<&VerifierLogLine> {
+        let pos = self
+            .lines
+            .iter()
+            .rposition(|line| matches!(line, VerifierLogLine::Insn { idx: i, .. } if *i == idx))?;
+        self.lines[..pos]
+            .iter()
+            .rev()
+            .take_while(|line| !matches!(line, VerifierLogLine::Insn { .. }))
+            .find(|line| matches!(line, VerifierLogLine::Source { .. }))
+    }
+}
+
+fn parse_line(line: &str) -> VerifierLogLine {
+    let line = line.trim_end();
+    parse_source(line)
+        .or_else(|| parse_stats(line))
+        .or_else(|| parse_branch(line))
+        .or_else(|| parse_insn(line))
+        .unwrap_or_else(|| VerifierLogLine::Other(line.to_owned()))
+}
+
+fn parse_source(line: &str) -> Option<VerifierLogLine> {
+    let text = line.strip_prefix("; ")?;
+    let location = text.rsplit_once(" @ ").and_then(|(code, loc)| {
+        let (file, line) = loc.rsplit_once(':')?;
+        Some((code, file, line.parse().ok()?))
+    });
+    Some(match location {
+        Some((code, file, line)) => VerifierLogLine::Source {
+            text: code.to_owned(),
+            file: Some(file.to_owned()),
+            line: Some(line),
+        },
+        None => VerifierLogLine::Source {
+            text: text.to_owned(),
+            file: None,
+            line: None,
+        },
+    })
+}
+
+fn parse_stats(line: &str) -> Option<VerifierLogLine> {
+    let rest = line.strip_prefix("processed ")?;
+    let mut words = rest.split_whitespace();
+    let mut stats = VerifierStats {
+        processed_insns: words.next()?.parse().ok()?,
+        ..Default::default()
+    };
+    if words.next()? != "insns" {
+        return None;
+    }
+    while let Some(key) = words.next() {
+        let Some(value) = words.next() else { break };
+        let value = value.trim_end_matches(')');
+        let field = match key {
+            "(limit" => &mut stats.insn_limit,
+            "max_states_per_insn" => &mut stats.max_states_per_insn,
+            "total_states" => &mut stats.total_states,
+            "peak_states" => &mut stats.peak_states,
+            "mark_read" => &mut stats.mark_read,
+            _ => continue,
+        };
+        *field = value.parse().ok()?;
+    }
+    Some(VerifierLogLine::Stats(stats))
+}
+
+fn parse_verification_time(line: &str) -> Option<u64> {
+    let rest = line.strip_prefix("verification time ")?;
+    rest.strip_suffix(" usec")?.parse().ok()
+}
+
+fn parse_stack_depth(line: &str) -> Option<Vec<u32>> {
+    let rest = line.strip_prefix("stack depth ")?;
+    rest.trim_end()
+        .split('+')
+        .map(|depth| depth.parse().ok())
+        .collect()
+}
+
+fn parse_branch(line: &str) -> Option<VerifierLogLine> {
+    let rest = line.strip_prefix("from ")?;
+    let (from, rest) = rest.split_once(" to ")?;
+    let (to, state) = rest.split_once(':')?;
+    Some(VerifierLogLine::Branch {
+        from: from.parse().ok()?,
+        to: to.parse().ok()?,
+        state: parse_state(state),
+    })
+}
+
+fn parse_insn(line: &str) -> Option<VerifierLogLine> {
+    let (idx, rest) = line.split_once(": ")?;
+    let idx = idx.parse().ok()?;
+
+    if let Some(rest) = rest.strip_prefix('(') {
+        let (code, text) = rest.split_once(") ")?;
+        let code = u8::from_str_radix(code, 16).ok()?;
+        let (text, state) = match split_top_level(text, ';') {
+            Some((text, state)) => (text.trim_end(), parse_state(state)),
+            None => (text, Vec::new()),
+        };
+        return Some(VerifierLogLine::Insn {
+            idx,
+            code,
+            text: text.to_owned(),
+            state,
+        });
+    }
+
+    let (frame, state) = match rest.strip_prefix("frame") {
+        Some(rest) => {
+            let (frame, state) = rest.split_once(':')?;
+            (Some(frame.parse().ok()?), state)
+        }
+        None => (None, rest),
+    };
+    let state = parse_state(state);
+    if state.is_empty() {
+        return None;
+    }
+    Some(VerifierLogLine::State { idx, frame, state })
+}
+
+/// Splits `s` at the first `sep` outside parentheses.
+fn split_top_level(s: &str, sep: char) -> Option<(&str, &str)> {
+    let mut depth = 0u32;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c == sep && depth == 0 => return Some((&s[..i], &s[i + c.len_utf8()..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses space-separated `name=value` entries, where values may contain
+/// spaces inside parentheses. Entries without `=` are skipped.
+fn parse_state(mut s: &str) -> Vec<RegState> {
+    let mut state = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return state;
+        }
+        let (entry, rest) = split_top_level(s, ' ').unwrap_or((s, ""));
+        s = rest;
+        let Some((name, value)) = entry.split_once('=') else {
+            continue;
+        };
+        let (name, written) = match name.strip_suffix("_w") {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        state.push(RegState {
+            name: name.to_owned(),
+            written,
+            value: value.to_owned(),
+        });
+    }
+}
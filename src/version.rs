@@ -0,0 +1,92 @@
+// src/version.rs
+
+//! Checking the linked libbpf against the bindings.
+//!
+//! The bindings describe one libbpf release, [`LibbpfVersion::BINDINGS`].
+//! When linking a system libbpf, the library found at runtime may be older,
+//! lacking functions and knowing only a prefix of the `*_opts` structs the
+//! bindings define. [`check_libbpf_version`] lets a binary detect this up
+//! front instead.
+//!
+//! ```no_run
+//! match libbpf_sys::version::check_libbpf_version() {
+//!     Ok(version) => println!("using libbpf {version}"),
+//!     Err(err) => eprintln!("warning: {err}; some features are unavailable"),
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::*;
+
+/// A libbpf `major.minor` version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LibbpfVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl LibbpfVersion {
+    /// The version the bindings were generated from.
+    pub const BINDINGS: Self = Self {
+        major: LIBBPF_MAJOR_VERSION,
+        minor: LIBBPF_MINOR_VERSION,
+    };
+
+    /// The version of the libbpf linked at runtime.
+    pub fn runtime() -> Self {
+        unsafe {
+            Self {
+                major: libbpf_major_version(),
+                minor: libbpf_minor_version(),
+            }
+        }
+    }
+
+    /// Whether a library of this version provides everything the bindings
+    /// for `bindings` declare: the same major version, and a minor version
+    /// no older.
+    pub fn supports(&self, bindings: Self) -> bool {
+        self.major == bindings.major && self.minor >= bindings.minor
+    }
+}
+
+impl fmt::Display for LibbpfVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The runtime libbpf does not support the bindings' version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LibbpfVersionMismatch {
+    pub runtime: LibbpfVersion,
+    pub bindings: LibbpfVersion,
+}
+
+impl fmt::Display for LibbpfVersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "libbpf {} is linked, but the bindings require libbpf {}",
+            self.runtime, self.bindings
+        )
+    }
+}
+
+impl Error for LibbpfVersionMismatch {}
+
+/// Checks that the libbpf linked at runtime supports
+/// [`LibbpfVersion::BINDINGS`], returning its version.
+pub fn check_libbpf_version() -> Result<LibbpfVersion, LibbpfVersionMismatch> {
+    let runtime = LibbpfVersion::runtime();
+    if runtime.supports(LibbpfVersion::BINDINGS) {
+        Ok(runtime)
+    } else {
+        Err(LibbpfVersionMismatch {
+            runtime,
+            bindings: LibbpfVersion::BINDINGS,
+        })
+    }
+}
@@ -0,0 +1,24 @@
+// src/vsnprintf.c
+
+#include <stdarg.h>
+#include <stddef.h>
+#include <stdio.h>
+
+/*
+ * vsnprintf() on a copy of ap, leaving ap itself usable by the caller.
+ *
+ * va_list is an array, a struct passed by reference or a plain pointer
+ * depending on the ABI, and va_copy() is the only portable way to duplicate
+ * one. Rust passes through the va_list argument its callback received, so it
+ * reaches this function exactly as libbpf passed it.
+ */
+int libbpf_sys_vsnprintf(char *buf, size_t size, const char *fmt, va_list ap)
+{
+	va_list copy;
+	int len;
+
+	va_copy(copy, ap);
+	len = vsnprintf(buf, size, fmt, copy);
+	va_end(copy);
+	return len;
+}
@@ -2,8 +2,16 @@
 
 #[cfg(test)]
 mod tests {
+    use libbpf_sys::headers::*;
+    use libbpf_sys::opts::*;
     use libbpf_sys::*;
+    #[cfg(not(feature = "syscall-only"))]
+    use libbpf_sys::{
+        btf_dump_writer::*, error::*, info::*, loaded::*, percpu::*, perfbuf::*, print::*,
+        prog_handler::*, ringbuf::*, skeleton::*, type_names::*, verifier_log::*, version::*,
+    };
 
+    #[cfg(not(feature = "syscall-only"))]
     unsafe extern "C" fn print_fn(
         _level: libbpf_print_level,
         _arg1: *const std::os::raw::c_char,
@@ -12,11 +20,1426 @@ mod tests {
         0
     }
 
+    /// libbpf's print callback is process-global.
+    #[cfg(not(feature = "syscall-only"))]
+    static PRINT_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[cfg(not(feature = "syscall-only"))]
     #[test]
     fn test() {
+        let _guard = PRINT_LOCK.lock().unwrap();
         unsafe {
             // just tests that we can call into the library
             assert!(libbpf_set_print(Some(print_fn as _)).is_some());
         }
     }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_set_print() {
+        use std::sync::{Arc, Mutex};
+
+        let _guard = PRINT_LOCK.lock().unwrap();
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+        set_print(Some(Arc::new(move |level, msg| {
+            sink.lock().unwrap().push((level, msg.to_string()));
+        })));
+
+        let path = c"/nonexistent/libbpf-sys-test.bpf.o";
+        let obj = unsafe { bpf_object__open_file(path.as_ptr(), std::ptr::null()) };
+        assert!(obj.is_null() || unsafe { libbpf_get_error(obj as _) } != 0);
+        assert!(set_print(None).is_some());
+
+        let messages = messages.lock().unwrap();
+        assert!(messages.iter().any(|(level, msg)| *level == LIBBPF_WARN
+            && msg.contains("/nonexistent/libbpf-sys-test.bpf.o")));
+
+        // A callback may replace itself without deadlocking.
+        let calls = Arc::new(Mutex::new(0));
+        let counter = calls.clone();
+        set_print(Some(Arc::new(move |_, _| {
+            *counter.lock().unwrap() += 1;
+            set_print(None);
+        })));
+        let obj = unsafe { bpf_object__open_file(path.as_ptr(), std::ptr::null()) };
+        assert!(obj.is_null() || unsafe { libbpf_get_error(obj as _) } != 0);
+        assert_eq!(*calls.lock().unwrap(), 1);
+        assert!(set_print(None).is_none());
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_btf_dump() {
+        unsafe {
+            let btf = btf__new_empty();
+            assert!(!btf.is_null());
+            let int_id = btf__add_int(btf, c"int".as_ptr(), 4, BTF_INT_SIGNED as _);
+            let struct_id = btf__add_struct(btf, c"pair".as_ptr(), 8);
+            assert_eq!(btf__add_field(btf, c"a".as_ptr(), int_id, 0, 0), 0);
+            assert_eq!(btf__add_field(btf, c"b".as_ptr(), int_id, 32, 0), 0);
+
+            let header = btf_dump_c_header(btf, "__PAIR_H__").unwrap();
+            assert!(header.starts_with("#ifndef __PAIR_H__\n"));
+            assert!(header.contains("struct pair {\n\tint a;\n\tint b;\n};"));
+            assert!(header.ends_with("#endif /* __PAIR_H__ */\n"));
+
+            let data = [1i32.to_ne_bytes(), (-2i32).to_ne_bytes()].concat();
+            let text = btf_dump_type_data_to_string(btf, struct_id as u32, &data, None).unwrap();
+            assert!(text.contains(".a = (int)1"), "{text}");
+            assert!(text.contains(".b = (int)-2"), "{text}");
+
+            btf__free(btf);
+        }
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_btf_accessors() {
+        use libbpf_sys::btf_accessors::*;
+
+        unsafe {
+            let btf = btf__new_empty();
+            let int_id = btf__add_int(btf, c"int".as_ptr(), 4, BTF_INT_SIGNED as _);
+            let s_id = btf__add_struct(btf, c"s".as_ptr(), 8);
+            btf__add_field(btf, c"a".as_ptr(), int_id, 0, 0);
+            btf__add_field(btf, c"b".as_ptr(), int_id, 35, 3);
+            let e_id = btf__add_enum64(btf, c"e".as_ptr(), 8, false);
+            btf__add_enum64_value(btf, c"BIG".as_ptr(), 0x1_2345_6789);
+            let proto_id = btf__add_func_proto(btf, int_id);
+            btf__add_func_param(btf, c"x".as_ptr(), int_id);
+            btf__add_func_param(btf, c"y".as_ptr(), s_id);
+            let arr_id = btf__add_array(btf, int_id, int_id, 16);
+            let var_id = btf__add_var(btf, c"v".as_ptr(), BTF_VAR_GLOBAL_ALLOCATED as _, int_id);
+            let sec_id = btf__add_datasec(btf, c".data".as_ptr(), 4);
+            btf__add_datasec_var_info(btf, var_id, 0, 4);
+
+            let ty = |id: i32| &*btf__type_by_id(btf, id as u32);
+
+            let t = ty(int_id);
+            assert!(btf_is_int(t));
+            assert_eq!(BtfKind::of(t), Some(BtfKind::Int));
+            assert_eq!(btf_int_encoding(t), BTF_INT_SIGNED as u8);
+            assert_eq!(btf_int_bits(t), 32);
+            assert_eq!(btf_int_offset(t), 0);
+
+            let t = ty(s_id);
+            assert!(btf_is_struct(t) && btf_is_composite(t) && btf_kflag(t));
+            assert_eq!(btf_vlen(t), 2);
+            assert_eq!(btf_members(t)[1].type_, int_id as u32);
+            assert_eq!(btf_member_bit_offset(t, 0), 0);
+            assert_eq!(btf_member_bit_offset(t, 1), 35);
+            assert_eq!(btf_member_bitfield_size(t, 0), 0);
+            assert_eq!(btf_member_bitfield_size(t, 1), 3);
+            // btf_dump reads the same bits through the C inlines.
+            let header = btf_dump_c_header(btf, "__S_H__").unwrap();
+            let bitfield = format!("\tint b: {};", btf_member_bitfield_size(t, 1));
+            assert!(header.contains(&bitfield), "{header}");
+
+            let t = ty(e_id);
+            assert!(btf_is_enum64(t) && btf_is_any_enum(t) && !btf_is_enum(t));
+            assert_eq!(btf_enum64_value(&btf_enum64(t)[0]), 0x1_2345_6789);
+
+            let t = ty(proto_id);
+            assert!(btf_is_func_proto(t));
+            let params = btf_params(t);
+            assert_eq!(params.len(), 2);
+            assert_eq!(params[1].type_, s_id as u32);
+
+            let t = ty(arr_id);
+            assert!(btf_is_array(t));
+            assert_eq!(btf_array(t).nelems, 16);
+
+            let t = ty(var_id);
+            assert!(btf_is_var(t));
+            assert_eq!(btf_var(t).linkage, BTF_VAR_GLOBAL_ALLOCATED);
+
+            let t = ty(sec_id);
+            assert!(btf_is_datasec(t));
+            let secinfos = btf_var_secinfos(t);
+            assert_eq!(secinfos.len(), 1);
+            assert_eq!(secinfos[0].type_, var_id as u32);
+            assert_eq!(secinfos[0].size, 4);
+
+            assert!(btf_is_void(ty(0)));
+
+            btf__free(btf);
+        }
+    }
+
+    /// Cross-checks `btf_enum` against libbpf's C inlines by rendering the
+    /// enum from the Rust accessors the way `btf_dump`, which uses the C
+    /// ones, does.
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_btf_enum() {
+        use libbpf_sys::btf_accessors::*;
+
+        unsafe {
+            let btf = btf__new_empty();
+            let e_id = btf__add_enum(btf, c"color".as_ptr(), 4);
+            btf__add_enum_value(btf, c"RED".as_ptr(), 1);
+            btf__add_enum_value(btf, c"GREEN".as_ptr(), -2);
+            btf__add_enum_value(btf, c"BLUE".as_ptr(), 0x7fff_ffff);
+
+            let t = &*btf__type_by_id(btf, e_id as u32);
+            assert!(btf_is_enum(t) && btf_is_any_enum(t) && !btf_is_enum64(t));
+            // A negative value marks the enum as signed.
+            assert!(btf_kflag(t));
+            let values = btf_enum(t);
+            assert_eq!(values.len(), 3);
+            assert_eq!(
+                values.iter().map(|v| v.val).collect::<Vec<_>>(),
+                [1, -2, 0x7fff_ffff]
+            );
+
+            let mut expected = String::from("enum color {\n");
+            for value in values {
+                let name = std::ffi::CStr::from_ptr(btf__name_by_offset(btf, value.name_off));
+                expected += &format!("\t{} = {},\n", name.to_str().unwrap(), value.val);
+            }
+            expected += "};";
+            let header = btf_dump_c_header(btf, "__COLOR_H__").unwrap();
+            assert!(header.contains(&expected), "{header}");
+
+            btf__free(btf);
+        }
+    }
+
+    /// Encodings as listed in the kernel's BPF selftests and `bpftool prog
+    /// dump xlated opcodes` output.
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn test_insn_encoding() {
+        use libbpf_sys::insn::*;
+
+        let bytes = |insns: &[bpf_insn]| -> Vec<u8> {
+            insns
+                .iter()
+                .flat_map(|insn| unsafe { std::mem::transmute::<bpf_insn, [u8; 8]>(*insn) })
+                .collect()
+        };
+        let cases: &[(&[bpf_insn], &[u8])] = &[
+            (
+                &[BPF_MOV64_IMM(BPF_REG_0, 0)],
+                &[0xb7, 0x00, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                &[BPF_MOV64_REG(BPF_REG_1, BPF_REG_10)],
+                &[0xbf, 0xa1, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                &[BPF_MOV32_IMM(BPF_REG_2, -1)],
+                &[0xb4, 0x02, 0, 0, 0xff, 0xff, 0xff, 0xff],
+            ),
+            (
+                &[BPF_ALU64_IMM(BPF_ADD, BPF_REG_2, -8)],
+                &[0x07, 0x02, 0, 0, 0xf8, 0xff, 0xff, 0xff],
+            ),
+            (
+                &[BPF_ALU32_REG(BPF_XOR, BPF_REG_3, BPF_REG_4)],
+                &[0xac, 0x43, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                &[BPF_ENDIAN(BPF_TO_BE, BPF_REG_1, 16)],
+                &[0xdc, 0x01, 0, 0, 16, 0, 0, 0],
+            ),
+            (
+                &[BPF_BSWAP(BPF_REG_1, 64)],
+                &[0xd7, 0x01, 0, 0, 64, 0, 0, 0],
+            ),
+            (
+                &[BPF_STX_MEM(BPF_DW, BPF_REG_10, BPF_REG_1, -8)],
+                &[0x7b, 0x1a, 0xf8, 0xff, 0, 0, 0, 0],
+            ),
+            (
+                &[BPF_LDX_MEM(BPF_W, BPF_REG_0, BPF_REG_1, 4)],
+                &[0x61, 0x10, 4, 0, 0, 0, 0, 0],
+            ),
+            (
+                &[BPF_ST_MEM(BPF_B, BPF_REG_10, -1, 7)],
+                &[0x72, 0x0a, 0xff, 0xff, 7, 0, 0, 0],
+            ),
+            (
+                &[BPF_ATOMIC_OP(
+                    BPF_DW,
+                    BPF_ADD | BPF_FETCH,
+                    BPF_REG_10,
+                    BPF_REG_1,
+                    -8,
+                )],
+                &[0xdb, 0x1a, 0xf8, 0xff, 0x01, 0, 0, 0],
+            ),
+            (
+                &[BPF_JMP_IMM(BPF_JEQ, BPF_REG_0, 0, 1)],
+                &[0x15, 0x00, 1, 0, 0, 0, 0, 0],
+            ),
+            (
+                &[BPF_JMP32_REG(BPF_JSGT, BPF_REG_1, BPF_REG_2, -3)],
+                &[0x6e, 0x21, 0xfd, 0xff, 0, 0, 0, 0],
+            ),
+            (&[BPF_JMP_A(2)], &[0x05, 0x00, 2, 0, 0, 0, 0, 0]),
+            (
+                &[BPF_EMIT_CALL(BPF_FUNC_map_lookup_elem)],
+                &[0x85, 0x00, 0, 0, 1, 0, 0, 0],
+            ),
+            (&[BPF_CALL_REL(5)], &[0x85, 0x10, 0, 0, 5, 0, 0, 0]),
+            (&[BPF_EXIT_INSN()], &[0x95, 0x00, 0, 0, 0, 0, 0, 0]),
+            (
+                &BPF_LD_MAP_FD(BPF_REG_1, 3),
+                &[0x18, 0x11, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                &BPF_LD_IMM64(BPF_REG_0, 0x1122334455667788),
+                &[
+                    0x18, 0x00, 0, 0, 0x88, 0x77, 0x66, 0x55, 0, 0, 0, 0, 0x44, 0x33, 0x22, 0x11,
+                ],
+            ),
+        ];
+        for (insns, expected) in cases {
+            assert_eq!(&bytes(insns), expected);
+        }
+
+        let insn = BPF_JMP32_REG(BPF_JSGT, BPF_REG_1, BPF_REG_2, -3);
+        assert_eq!((insn.dst_reg(), insn.src_reg()), (1, 2));
+    }
+
+    #[test]
+    fn test_disasm() {
+        use libbpf_sys::disasm::*;
+        use libbpf_sys::insn::*;
+
+        let prog = [
+            &[BPF_MOV64_IMM(BPF_REG_0, 0)][..],
+            &[BPF_STX_MEM(BPF_W, BPF_REG_10, BPF_REG_0, -4)],
+            &[BPF_MOV64_REG(BPF_REG_2, BPF_REG_10)],
+            &[BPF_ALU64_IMM(BPF_ADD, BPF_REG_2, -4)],
+            &BPF_LD_MAP_FD(BPF_REG_1, 7),
+            &[BPF_EMIT_CALL(BPF_FUNC_map_lookup_elem)],
+            &[BPF_JMP_IMM(BPF_JEQ, BPF_REG_0, 0, 2)],
+            &[BPF_LDX_MEM(BPF_DW, BPF_REG_1, BPF_REG_0, 0)],
+            &[BPF_ATOMIC_OP(BPF_DW, BPF_ADD, BPF_REG_0, BPF_REG_1, 8)],
+            &[BPF_JMP32_REG(BPF_JSLT, BPF_REG_1, BPF_REG_2, -1)],
+            &[BPF_ALU32_IMM(BPF_RSH, BPF_REG_3, 4)],
+            &[BPF_ENDIAN(BPF_TO_BE, BPF_REG_3, 32)],
+            &[BPF_MOVSX64_REG(BPF_REG_4, BPF_REG_3, 16)],
+            &[BPF_ATOMIC_OP(
+                BPF_W,
+                BPF_XOR | BPF_FETCH,
+                BPF_REG_10,
+                BPF_REG_5,
+                -8,
+            )],
+            &[BPF_CALL_REL(3)],
+            &[BPF_EXIT_INSN()],
+        ]
+        .concat();
+
+        assert_eq!(
+            disassemble(&prog, MapRefs::Fd),
+            "0: (b7) r0 = 0\n\
+             1: (63) *(u32 *)(r10 -4) = r0\n\
+             2: (bf) r2 = r10\n\
+             3: (07) r2 += -4\n\
+             4: (18) r1 = map[fd:7]\n\
+             6: (85) call bpf_map_lookup_elem#1\n\
+             7: (15) if r0 == 0x0 goto pc+2\n\
+             8: (79) r1 = *(u64 *)(r0 +0)\n\
+             9: (db) lock *(u64 *)(r0 +8) += r1\n\
+             10: (ce) if w1 s< w2 goto pc-1\n\
+             11: (74) w3 >>= 4\n\
+             12: (dc) r3 = be32 r3\n\
+             13: (bf) r4 = (s16)r3\n\
+             14: (c3) r5 = atomic_fetch_xor((u32 *)(r10 -8), r5)\n\
+             15: (85) call pc+3\n\
+             16: (95) exit\n"
+        );
+        assert_eq!(helper_name(BPF_FUNC_ktime_get_ns), Some("bpf_ktime_get_ns"));
+    }
+
+    #[test]
+    fn test_asm() {
+        use libbpf_sys::asm::*;
+        use libbpf_sys::disasm::*;
+
+        let listing = "0: (b7) r0 = 0\n\
+                       1: (63) *(u32 *)(r10 -4) = r0\n\
+                       2: (18) r1 = map[fd:7]\n\
+                       4: (85) call bpf_map_lookup_elem#1\n\
+                       5: (15) if r0 == 0x0 goto pc+1\n\
+                       6: (db) lock *(u64 *)(r0 +8) += r1\n\
+                       7: (95) exit\n";
+        let insns = assemble(listing).unwrap();
+        assert_eq!(disassemble(&insns, MapRefs::Fd), listing);
+
+        let insns = assemble_with_maps(
+            "
+                r2 = r10
+                r2 += -4
+                r1 = map[counts]       ; placeholder
+                call map_lookup_elem
+                if r0 != 0 goto found
+                r0 = 0x100000000
+                exit
+            found:
+                r0 = *(u64 *)(r0 +0)
+                exit
+            ",
+            &[("counts", 3)],
+        )
+        .unwrap();
+        assert_eq!(
+            disassemble(&insns, MapRefs::Fd),
+            "0: (bf) r2 = r10\n\
+             1: (07) r2 += -4\n\
+             2: (18) r1 = map[fd:3]\n\
+             4: (85) call bpf_map_lookup_elem#1\n\
+             5: (55) if r0 != 0x0 goto pc+3\n\
+             6: (18) r0 = 0x100000000\n\
+             8: (95) exit\n\
+             9: (79) r0 = *(u64 *)(r0 +0)\n\
+             10: (95) exit\n"
+        );
+
+        let err = assemble("r0 = 0\ngoto nowhere").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        // Stores truncate wide immediates to one slot, unlike `rN = imm64`.
+        let insns = assemble(
+            "
+                *(u32 *)(r10 -4) = 0xffffffff # unsigned spelling
+                if r1 == 0 goto out
+                r0 = 0xffffffff
+            out:
+                exit
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            disassemble(&insns, MapRefs::Fd),
+            "0: (62) *(u32 *)(r10 -4) = -1\n\
+             1: (15) if r1 == 0x0 goto pc+2\n\
+             2: (18) r0 = 0xffffffff\n\
+             4: (95) exit\n"
+        );
+        let err = assemble("w0 = 0x100000000").unwrap_err();
+        assert!(err.msg.contains("32 bits"), "{err}");
+
+        let listing = "0: (bf) r1 = addr_space_cast(r2, 0, 1)\n";
+        assert_eq!(
+            disassemble(&assemble(listing).unwrap(), MapRefs::Fd),
+            listing
+        );
+    }
+
+    #[test]
+    fn test_anon_types() {
+        let mut attr = bpf_attr::from(bpf_attr_map_create {
+            map_type: BPF_MAP_TYPE_ARRAY,
+            max_entries: 4,
+            ..Default::default()
+        });
+        unsafe {
+            assert_eq!(attr.map_create().max_entries, 4);
+            attr.prog_load_mut().insn_cnt = 7;
+            assert_eq!(attr.__bindgen_anon_3.insn_cnt, 7);
+        }
+
+        let mut pattr = perf_event_attr::default();
+        pattr.sample_mut().sample_freq = 99;
+        assert_eq!(unsafe { pattr.sample().sample_period }, 99);
+
+        let mut info = bpf_link_info::default();
+        unsafe {
+            info.xdp_mut().ifindex = 3;
+            assert_eq!(info.__bindgen_anon_1.xdp.ifindex, 3);
+            assert_eq!(info.perf_event().type_, 3);
+        }
+    }
+
+    #[cfg(feature = "syscall")]
+    #[test]
+    fn test_syscall() {
+        use libbpf_sys::syscall::*;
+        use std::os::fd::AsRawFd;
+
+        let mut create = bpf_attr_map_create {
+            map_type: BPF_MAP_TYPE_ARRAY,
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1,
+            ..Default::default()
+        };
+        let map = match unsafe { sys_bpf_fd(BPF_MAP_CREATE, &mut create) } {
+            Ok(map) => map,
+            // Unprivileged, or bpf(2) is filtered out.
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::Unsupported
+                ) =>
+            {
+                return
+            }
+            Err(err) => panic!("BPF_MAP_CREATE: {err}"),
+        };
+
+        let key = 0u32;
+        let value = 42u64;
+        let mut elem = bpf_attr_map_elem {
+            map_fd: map.as_raw_fd() as u32,
+            key: &key as *const _ as u64,
+            ..Default::default()
+        };
+        elem.__bindgen_anon_1.value = &value as *const _ as u64;
+        unsafe { sys_bpf(BPF_MAP_UPDATE_ELEM, &mut elem) }.unwrap();
+
+        let mut out = 0u64;
+        elem.__bindgen_anon_1.value = &mut out as *mut _ as u64;
+        unsafe { sys_bpf(BPF_MAP_LOOKUP_ELEM, &mut elem) }.unwrap();
+        assert_eq!(out, 42);
+
+        let mut info = bpf_map_info::default();
+        let mut attr = bpf_attr_info {
+            bpf_fd: map.as_raw_fd() as u32,
+            info_len: std::mem::size_of::<bpf_map_info>() as u32,
+            info: &mut info as *mut _ as u64,
+        };
+        unsafe { sys_bpf(BPF_OBJ_GET_INFO_BY_FD, &mut attr) }.unwrap();
+        assert_eq!(info.value_size, 8);
+        assert!(attr.info_len > 0);
+
+        let mut bad = bpf_attr_map_create {
+            map_type: u32::MAX,
+            ..Default::default()
+        };
+        let err = unsafe { sys_bpf_fd(BPF_MAP_CREATE, &mut bad) }.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    /// Creates a map, or returns `None` if bpf(2) is unavailable to us.
+    #[cfg(not(feature = "syscall-only"))]
+    fn create_map(map_type: bpf_map_type, max_entries: u32) -> Option<std::os::fd::OwnedFd> {
+        use std::os::fd::FromRawFd;
+
+        let (key_size, value_size) = match map_type {
+            BPF_MAP_TYPE_RINGBUF => (0, 0),
+            _ => (4, 4),
+        };
+        let ptr = std::ptr::null();
+        let fd =
+            unsafe { bpf_map_create(map_type, ptr, key_size, value_size, max_entries, ptr.cast()) };
+        (fd >= 0).then(|| unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) })
+    }
+
+    /// Assembles and loads a socket filter that uses the map `map`.
+    #[cfg(not(feature = "syscall-only"))]
+    fn load_socket_filter(src: &str, map: &std::os::fd::OwnedFd) -> std::os::fd::OwnedFd {
+        use std::os::fd::{AsRawFd, FromRawFd};
+
+        let insns = libbpf_sys::asm::assemble_with_maps(src, &[("map", map.as_raw_fd())]).unwrap();
+        let fd = unsafe {
+            bpf_prog_load(
+                BPF_PROG_TYPE_SOCKET_FILTER,
+                std::ptr::null(),
+                c"GPL".as_ptr(),
+                insns.as_ptr(),
+                insns.len() as _,
+                std::ptr::null_mut(),
+            )
+        };
+        assert!(
+            fd >= 0,
+            "bpf_prog_load: {}",
+            std::io::Error::last_os_error()
+        );
+        unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) }
+    }
+
+    /// Runs a socket filter once on an empty packet.
+    #[cfg(not(feature = "syscall-only"))]
+    fn test_run(prog: &std::os::fd::OwnedFd) {
+        use std::os::fd::AsRawFd;
+
+        let packet = [0u8; 64];
+        let mut opts = libbpf_opts!(bpf_test_run_opts {
+            data_in: packet.as_ptr().cast(),
+            data_size_in: packet.len() as u32,
+        });
+        assert_eq!(
+            unsafe { bpf_prog_test_run_opts(prog.as_raw_fd(), &mut opts) },
+            0
+        );
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_ring_buffer() {
+        use std::os::fd::AsRawFd;
+
+        let Some(map) = create_map(BPF_MAP_TYPE_RINGBUF, 4096) else {
+            return;
+        };
+        let prog = load_socket_filter(
+            "
+                r1 = 42
+                *(u64 *)(r10 -8) = r1
+                r1 = map[map]
+                r2 = r10
+                r2 += -8
+                r3 = 8
+                r4 = 0
+                call bpf_ringbuf_output
+                r0 = 0
+                exit
+            ",
+            &map,
+        );
+
+        let mut seen = Vec::new();
+        {
+            let mut rb = RingBuffer::new(map.as_raw_fd(), |data| {
+                seen.push(u64::from_ne_bytes(data.try_into().unwrap()));
+                0
+            })
+            .unwrap();
+            test_run(&prog);
+            test_run(&prog);
+            assert_eq!(rb.consume().unwrap(), 2);
+            assert_eq!(rb.poll(Some(std::time::Duration::ZERO)).unwrap(), 0);
+        }
+        assert_eq!(seen, [42, 42]);
+
+        let mut rb = RingBuffer::new(map.as_raw_fd(), |_| panic!("boom")).unwrap();
+        test_run(&prog);
+        assert!(rb.consume().is_err());
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_perf_buffer() {
+        use std::os::fd::AsRawFd;
+
+        let cpus = unsafe { libbpf_num_possible_cpus() } as u32;
+        let Some(map) = create_map(BPF_MAP_TYPE_PERF_EVENT_ARRAY, cpus) else {
+            return;
+        };
+        let prog = load_socket_filter(
+            "
+                r6 = r1
+                r1 = 42
+                *(u64 *)(r10 -8) = r1
+                r1 = r6
+                r2 = map[map]
+                r3 = 0xffffffff ll
+                r4 = r10
+                r4 += -8
+                r5 = 8
+                call bpf_perf_event_output
+                r0 = 0
+                exit
+            ",
+            &map,
+        );
+
+        let mut seen = Vec::new();
+        {
+            let mut pb = PerfBuffer::new(
+                map.as_raw_fd(),
+                1,
+                |_cpu, data| seen.push(u64::from_ne_bytes(data[..8].try_into().unwrap())),
+                |_cpu, _cnt| {},
+            )
+            .unwrap();
+            assert_eq!(pb.buffer_cnt(), cpus as usize);
+            test_run(&prog);
+            pb.consume().unwrap();
+        }
+        assert_eq!(seen, [42]);
+
+        let mut attr = perf_event_attr {
+            type_: PERF_TYPE_SOFTWARE,
+            size: std::mem::size_of::<perf_event_attr>() as u32,
+            config: PERF_COUNT_SW_BPF_OUTPUT as u64,
+            sample_type: PERF_SAMPLE_RAW as u64,
+            ..Default::default()
+        };
+        attr.sample_mut().sample_period = 1;
+        attr.wakeup_mut().wakeup_events = 1;
+        let mut records = Vec::new();
+        {
+            let mut pb =
+                PerfBuffer::new_raw(map.as_raw_fd(), 1, &mut attr, |_cpu, header, body| {
+                    records.push((PerfRecordType::of(header), body.len()));
+                    LIBBPF_PERF_EVENT_CONT
+                })
+                .unwrap();
+            test_run(&prog);
+            pb.consume().unwrap();
+        }
+        // A u32 size, then the 8 bytes of data padded to 8-byte alignment.
+        assert_eq!(records, [(Some(PerfRecordType::Sample), 16)]);
+
+        // After a panic, the rest of the batch is not passed to the closure.
+        let mut calls = 0;
+        {
+            let mut pb = PerfBuffer::new(
+                map.as_raw_fd(),
+                1,
+                |_, _| {
+                    calls += 1;
+                    panic!("boom")
+                },
+                |_, _| {},
+            )
+            .unwrap();
+            test_run(&prog);
+            test_run(&prog);
+            assert!(pb.consume().is_err());
+        }
+        assert_eq!(calls, 1);
+    }
+
+    /// Runs every callback of a handler on an object with a program in its
+    /// section, including one that panics.
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_prog_handler() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls: Arc<[AtomicUsize; 3]> = Arc::new(Default::default());
+        let (setup, prepare_load, attach) = (calls.clone(), calls.clone(), calls.clone());
+        // Registered first and dropped last: libbpf before 1.2 corrupts its
+        // registry if it empties and handlers are registered again.
+        let _handler = ProgHandler::register(
+            Some("libbpf_sys_object+"),
+            BPF_PROG_TYPE_SOCKET_FILTER,
+            0,
+            ProgHandlerCallbacks {
+                setup: Some(Box::new(move |_| {
+                    setup[0].fetch_add(1, Ordering::SeqCst);
+                    0
+                })),
+                prepare_load: Some(Box::new(move |_, _| {
+                    prepare_load[1].fetch_add(1, Ordering::SeqCst);
+                    0
+                })),
+                attach: Some(Box::new(move |_| {
+                    attach[2].fetch_add(1, Ordering::SeqCst);
+                    panic!("boom")
+                })),
+            },
+        )
+        .unwrap();
+        let count = |i: usize| calls[i].load(Ordering::SeqCst);
+
+        let handler = ProgHandler::register(
+            Some("libbpf_sys_test+"),
+            BPF_PROG_TYPE_KPROBE,
+            0,
+            ProgHandlerCallbacks {
+                setup: Some(Box::new(|_| 0)),
+                attach: Some(Box::new(|_| Ok(std::ptr::null_mut()))),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let id = handler.id();
+        assert!(id > 0);
+        drop(handler);
+        assert!(unsafe { libbpf_unregister_prog_handler(id) } < 0);
+
+        assert!(
+            ProgHandler::register(Some("nul\0"), BPF_PROG_TYPE_KPROBE, 0, Default::default())
+                .is_err()
+        );
+
+        #[cfg(target_endian = "little")]
+        {
+            let elf = bpf_object_with_section("libbpf_sys_object/prog");
+            let obj = unsafe {
+                bpf_object__open_mem(elf.as_ptr().cast(), elf.len() as _, std::ptr::null())
+            };
+            assert!(unsafe { libbpf_get_error(obj as _) } == 0 && !obj.is_null());
+            let prog = unsafe { bpf_object__find_program_by_name(obj, c"prog_main".as_ptr()) };
+            assert!(!prog.is_null());
+            assert_eq!(
+                unsafe { bpf_program__type(prog) },
+                BPF_PROG_TYPE_SOCKET_FILTER
+            );
+            assert_eq!(count(0), 1);
+
+            if create_map(BPF_MAP_TYPE_ARRAY, 1).is_some() {
+                assert_eq!(unsafe { bpf_object__load(obj) }, 0);
+                assert_eq!(count(1), 1);
+
+                // The panic is reported to libbpf as -EINVAL.
+                let link = unsafe { bpf_program__attach(prog) };
+                assert_eq!(unsafe { libbpf_get_error(link as _) }, -22);
+                assert_eq!(count(2), 1);
+            }
+            unsafe { bpf_object__close(obj) };
+        }
+    }
+
+    /// A minimal relocatable BPF object: a `socket` program `prog_main`
+    /// returning 0, a GPL license, and an 8-byte `.bss`.
+    #[cfg(not(feature = "syscall-only"))]
+    #[cfg(target_endian = "little")]
+    fn minimal_bpf_object() -> Vec<u8> {
+        bpf_object_with_section("socket")
+    }
+
+    /// [`minimal_bpf_object`], with the program in section `sec`.
+    #[cfg(not(feature = "syscall-only"))]
+    #[cfg(target_endian = "little")]
+    fn bpf_object_with_section(sec: &str) -> Vec<u8> {
+        use libbpf_sys::insn::*;
+
+        let insns = [BPF_MOV64_IMM(BPF_REG_0, 0), BPF_EXIT_INSN()];
+        let text: Vec<u8> = insns
+            .iter()
+            .flat_map(|insn| unsafe { std::mem::transmute::<bpf_insn, [u8; 8]>(*insn) })
+            .collect();
+        let mut shstrtab = vec![0u8];
+        let names: Vec<u32> = [sec, "license", ".bss", ".symtab", ".strtab", ".shstrtab"]
+            .iter()
+            .map(|name| {
+                let offset = shstrtab.len() as u32;
+                shstrtab.extend_from_slice(name.as_bytes());
+                shstrtab.push(0);
+                offset
+            })
+            .collect();
+        let strtab = b"\0prog_main\0";
+        let mut symtab = vec![0u8; 24];
+        symtab.extend_from_slice(&1u32.to_le_bytes()); // st_name
+        symtab.push(0x12); // STB_GLOBAL, STT_FUNC
+        symtab.push(0); // st_other
+        symtab.extend_from_slice(&1u16.to_le_bytes()); // st_shndx
+        symtab.extend_from_slice(&0u64.to_le_bytes()); // st_value
+        symtab.extend_from_slice(&(text.len() as u64).to_le_bytes()); // st_size
+
+        // (name, type, flags, contents, size, link, info, align, entsize)
+        type Section<'a> = (u32, u32, u64, &'a [u8], usize, u32, u32, u64, u64);
+        let sections: [Section; 6] = [
+            (names[0], 1, 0x6, &text, text.len(), 0, 0, 8, 0),
+            (names[1], 1, 0x3, b"GPL\0", 4, 0, 0, 1, 0),
+            (names[2], 8, 0x3, &[], 8, 0, 0, 8, 0),
+            (names[3], 2, 0, &symtab, symtab.len(), 5, 1, 8, 24),
+            (names[4], 3, 0, strtab, strtab.len(), 0, 0, 1, 0),
+            (names[5], 3, 0, &shstrtab, shstrtab.len(), 0, 0, 1, 0),
+        ];
+
+        let mut elf = vec![0u8; 64];
+        let mut headers = vec![0u8; 64];
+        for (name, ty, flags, contents, size, link, info, align, entsize) in sections {
+            while elf.len() % 8 != 0 {
+                elf.push(0);
+            }
+            headers.extend_from_slice(&name.to_le_bytes());
+            headers.extend_from_slice(&ty.to_le_bytes());
+            headers.extend_from_slice(&flags.to_le_bytes());
+            headers.extend_from_slice(&0u64.to_le_bytes()); // sh_addr
+            headers.extend_from_slice(&(elf.len() as u64).to_le_bytes());
+            headers.extend_from_slice(&(size as u64).to_le_bytes());
+            headers.extend_from_slice(&link.to_le_bytes());
+            headers.extend_from_slice(&info.to_le_bytes());
+            headers.extend_from_slice(&align.to_le_bytes());
+            headers.extend_from_slice(&entsize.to_le_bytes());
+            elf.extend_from_slice(contents);
+        }
+        while elf.len() % 8 != 0 {
+            elf.push(0);
+        }
+        let shoff = elf.len() as u64;
+        elf.extend_from_slice(&headers);
+
+        elf[..16].copy_from_slice(b"\x7fELF\x02\x01\x01\0\0\0\0\0\0\0\0\0");
+        elf[16..18].copy_from_slice(&1u16.to_le_bytes()); // ET_REL
+        elf[18..20].copy_from_slice(&247u16.to_le_bytes()); // EM_BPF
+        elf[20..24].copy_from_slice(&1u32.to_le_bytes());
+        elf[40..48].copy_from_slice(&shoff.to_le_bytes());
+        elf[52..54].copy_from_slice(&64u16.to_le_bytes()); // e_ehsize
+        elf[58..60].copy_from_slice(&64u16.to_le_bytes()); // e_shentsize
+        elf[60..62].copy_from_slice(&7u16.to_le_bytes()); // e_shnum
+        elf[62..64].copy_from_slice(&6u16.to_le_bytes()); // e_shstrndx
+        elf
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn test_skeleton() {
+        if create_map(BPF_MAP_TYPE_ARRAY, 1).is_none() {
+            return;
+        }
+        let elf = minimal_bpf_object();
+        let mut skel = ObjectSkeletonBuilder::new("skel", &elf)
+            .map(".bss", true)
+            .prog("prog_main")
+            .build()
+            .unwrap();
+        assert!(skel.object().is_null());
+
+        skel.open(None).unwrap();
+        assert!(!skel.object().is_null());
+        let prog = skel.prog("prog_main").unwrap();
+        assert_eq!(
+            unsafe { bpf_program__type(prog) },
+            BPF_PROG_TYPE_SOCKET_FILTER
+        );
+        let map = skel.map(".bss").unwrap();
+        assert!(skel.prog("missing").is_none());
+        // Initial contents, before the map exists.
+        unsafe { skel.mmaped(".bss").unwrap().cast::<u64>().write(7) };
+
+        skel.load().unwrap();
+        let lookup = || {
+            let (key, mut value) = (0u32, 0u64);
+            let fd = unsafe { bpf_map__fd(map) };
+            let ret = unsafe {
+                bpf_map_lookup_elem(fd, &key as *const _ as _, &mut value as *mut _ as _)
+            };
+            assert_eq!(ret, 0);
+            value
+        };
+        assert_eq!(lookup(), 7);
+        // Whether libbpf maps global data without BTF depends on its version.
+        if let Some(bss) = skel.mmaped(".bss") {
+            unsafe { bss.cast::<u64>().write(9) };
+            assert_eq!(lookup(), 9);
+        }
+    }
+
+    #[test]
+    fn test_opts_sized() {
+        let opts = bpf_object_open_opts::new_sized();
+        assert_eq!(
+            opts.sz,
+            std::mem::size_of::<bpf_object_open_opts>() as size_t
+        );
+
+        let opts = libbpf_opts!(bpf_kprobe_opts {
+            bpf_cookie: 42,
+            retprobe: true,
+        });
+        assert_eq!(opts.sz, std::mem::size_of::<bpf_kprobe_opts>() as size_t);
+        assert_eq!(opts.bpf_cookie, 42);
+        assert!(opts.retprobe);
+        assert_eq!(opts.offset, 0);
+
+        let hook = libbpf_opts!(bpf_tc_hook {
+            ifindex: 1,
+            attach_point: BPF_TC_INGRESS,
+        });
+        assert_eq!(hook.sz, std::mem::size_of::<bpf_tc_hook>() as size_t);
+        assert_eq!(hook.ifindex, 1);
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_libbpf_error() {
+        assert_eq!(LibbpfError::check(3), Ok(3));
+        let err = LibbpfError::check(-(LIBBPF_ERRNO__VERIFY as i32)).unwrap_err();
+        assert_eq!(err.libbpf_errno(), Some(LIBBPF_ERRNO__VERIFY));
+        assert_eq!(err.raw_os_error(), None);
+        assert!(err.to_string().contains("verifier"), "{err}");
+        assert_eq!(std::io::Error::from(err).kind(), std::io::ErrorKind::Other);
+
+        let path = std::ffi::CString::new("/nonexistent.bpf.o").unwrap();
+        let err = LibbpfError::check_ptr(unsafe { bpf_object__open(path.as_ptr()) }).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(2));
+        assert_eq!(
+            std::io::Error::from(err).kind(),
+            std::io::ErrorKind::NotFound
+        );
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_libbpf_version() {
+        let runtime = LibbpfVersion::runtime();
+        let string = unsafe { std::ffi::CStr::from_ptr(libbpf_version_string()) };
+        assert_eq!(string.to_str().unwrap(), format!("v{runtime}"));
+
+        let bindings = LibbpfVersion::BINDINGS;
+        assert_eq!(
+            bindings.to_string(),
+            format!("{LIBBPF_MAJOR_VERSION}.{LIBBPF_MINOR_VERSION}")
+        );
+        assert!(bindings.supports(bindings));
+        match check_libbpf_version() {
+            Ok(version) => assert!(version >= bindings),
+            Err(err) => {
+                assert_eq!(err.runtime, runtime);
+                assert!(!runtime.supports(bindings));
+            }
+        }
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_type_names() {
+        assert_eq!(ProgType(BPF_PROG_TYPE_XDP).to_string(), "xdp");
+        assert_eq!("xdp".parse(), Ok(ProgType(BPF_PROG_TYPE_XDP)));
+        assert_eq!("hash".parse(), Ok(MapType(BPF_MAP_TYPE_HASH)));
+        assert_eq!(
+            "cgroup_inet_ingress".parse(),
+            Ok(AttachType(BPF_CGROUP_INET_INGRESS))
+        );
+        assert_eq!("tracing".parse(), Ok(LinkType(BPF_LINK_TYPE_TRACING)));
+        assert!("no_such_type".parse::<MapType>().is_err());
+
+        assert_eq!(ProgType::iter().count(), __MAX_BPF_PROG_TYPE as usize);
+        for ty in MapType::iter().chain([MapType(1000)]) {
+            assert_eq!(ty.to_string().parse(), Ok(ty));
+        }
+        assert_eq!(MapType(1000).name(), None);
+        assert_eq!(MapType(1000).to_string(), "1000");
+
+        assert_eq!(
+            ProgType::from_section("kprobe/do_exit"),
+            Some((ProgType(BPF_PROG_TYPE_KPROBE), AttachType(0)))
+        );
+        assert_eq!(
+            AttachType::from_section("cgroup_skb/egress"),
+            Some(AttachType(BPF_CGROUP_INET_EGRESS))
+        );
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_size_t_abi() {
+        use std::mem::{align_of, size_of};
+        use std::os::raw::c_ulong;
+
+        assert_eq!(size_of::<size_t>(), size_of::<c_ulong>());
+        assert_eq!(align_of::<size_t>(), align_of::<c_ulong>());
+        assert_eq!(size_of::<size_t>(), size_of::<usize>());
+        assert_eq!(align_of::<size_t>(), align_of::<usize>());
+
+        #[cfg(feature = "size-t-is-usize")]
+        let _: unsafe extern "C" fn(
+            *const std::os::raw::c_void,
+            usize,
+            *const bpf_object_open_opts,
+        ) -> *mut bpf_object = bpf_object__open_mem;
+
+        let data = [0u8; 4];
+        let obj = unsafe {
+            bpf_object__open_mem(data.as_ptr().cast(), data.len() as size_t, std::ptr::null())
+        };
+        assert!(obj.is_null());
+    }
+
+    #[cfg(feature = "vendored-libbpf")]
+    #[test]
+    fn test_write_headers() {
+        let dir = std::env::temp_dir().join(format!("libbpf-sys-headers-{}", std::process::id()));
+        write_headers(&dir).unwrap();
+        let helpers = std::fs::read_to_string(dir.join("bpf/bpf_helpers.h")).unwrap();
+        assert!(helpers.contains("#include \"bpf_helper_defs.h\""));
+        let uapi = std::fs::read_to_string(dir.join("linux/bpf.h")).unwrap();
+        assert!(uapi.contains("#include <linux/bpf_common.h>"));
+
+        // The user-space API headers must resolve all of their includes
+        // against the written directory plus the system C headers.
+        let tu = dir.join("tu.c");
+        std::fs::write(
+            &tu,
+            "#include <bpf/libbpf.h>\n#include <bpf/bpf.h>\n#include <bpf/btf.h>\n",
+        )
+        .unwrap();
+        let cc = std::env::var_os("CC").unwrap_or_else(|| "cc".into());
+        if let Ok(status) = std::process::Command::new(cc)
+            .arg("-fsyntax-only")
+            .arg(format!("-I{}", dir.display()))
+            .arg(&tu)
+            .status()
+        {
+            assert!(status.success());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_system_include_dirs() {
+        match system_include_dirs() {
+            Ok(dirs) => {
+                assert!(dirs[0].join("bpf/libbpf.h").is_file());
+                assert!(dirs.iter().any(|dir| dir.join("linux/bpf.h").is_file()));
+            }
+            Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::NotFound),
+        }
+        // musl has no multiarch directory.
+        if let Ok(dirs) = system_include_dirs_for_target("x86_64-unknown-linux-musl") {
+            assert!(dirs.iter().all(|dir| !dir.ends_with("x86_64-linux-gnu")));
+        }
+    }
+
+    #[cfg(feature = "perf-event")]
+    #[test]
+    fn test_perf_event() {
+        use libbpf_sys::perf_event::*;
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            assert_eq!(PERF_EVENT_IOC_ENABLE, 0x2400);
+            assert_eq!(PERF_EVENT_IOC_PERIOD, 0x40082404);
+            assert_eq!(PERF_EVENT_IOC_ID, 0x80082407);
+            assert_eq!(PERF_EVENT_IOC_SET_BPF, 0x40042408);
+        }
+
+        let builder = PerfEventBuilder::software(PERF_COUNT_SW_CPU_CLOCK).sample_freq(99);
+        assert_eq!(builder.attr().type_, PERF_TYPE_SOFTWARE);
+        assert_eq!(builder.attr().freq(), 1);
+        assert_eq!(builder.attr().disabled(), 1);
+        assert_eq!(unsafe { builder.attr().sample().sample_freq }, 99);
+
+        // perf events are unavailable, e.g. due to `perf_event_paranoid`.
+        let Ok(event) = PerfEventBuilder::software(PERF_COUNT_SW_TASK_CLOCK).open(0, -1, None)
+        else {
+            return;
+        };
+        event.reset().unwrap();
+        event.enable().unwrap();
+        event.disable().unwrap();
+        assert_ne!(event.id().unwrap(), 0);
+
+        let events = builder.open_per_cpu().unwrap();
+        assert!(!events.is_empty());
+        assert!(events.windows(2).all(|pair| pair[0].cpu() < pair[1].cpu()));
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_info() {
+        use std::os::fd::{AsFd, AsRawFd, FromRawFd, OwnedFd};
+
+        let Some(map) = create_map(BPF_MAP_TYPE_ARRAY, 1) else {
+            return;
+        };
+        let map_info = MapInfo::from_fd(map.as_fd()).unwrap();
+        assert_eq!(map_info.info.type_, BPF_MAP_TYPE_ARRAY);
+        assert_eq!(map_info.info.max_entries, 1);
+
+        let prog = load_socket_filter(
+            "r1 = map[map]\n\
+             r0 = 0\n\
+             exit",
+            &map,
+        );
+        let info = ProgInfo::from_fd(prog.as_fd()).unwrap();
+        assert_eq!(info.info.type_, BPF_PROG_TYPE_SOCKET_FILTER);
+        assert_eq!(info.map_ids, [map_info.info.id]);
+        assert_eq!(info.xlated_prog_insns.len(), 4);
+        assert_eq!(info.prog_tags.len(), 1);
+        assert_eq!(info.prog_tags[0], info.info.tag);
+        assert_eq!(info.info.map_ids, 0);
+
+        unsafe {
+            let btf = btf__new_empty();
+            btf__add_int(btf, c"int".as_ptr(), 4, BTF_INT_SIGNED as _);
+            assert_eq!(btf__load_into_kernel(btf), 0);
+            let fd = std::os::fd::BorrowedFd::borrow_raw(btf__fd(btf));
+            let info = BtfInfo::from_fd(fd).unwrap();
+            assert!(info.name.is_empty());
+            let mut size = 0;
+            let raw = btf__raw_data(btf, &mut size);
+            assert_eq!(
+                info.data,
+                std::slice::from_raw_parts(raw.cast::<u8>(), size as usize)
+            );
+            btf__free(btf);
+        }
+
+        let insns = libbpf_sys::asm::assemble("r0 = 0\nexit").unwrap();
+        let prog = unsafe {
+            let fd = bpf_prog_load(
+                BPF_PROG_TYPE_RAW_TRACEPOINT,
+                std::ptr::null(),
+                c"GPL".as_ptr(),
+                insns.as_ptr(),
+                insns.len() as _,
+                std::ptr::null_mut(),
+            );
+            assert!(fd >= 0);
+            OwnedFd::from_raw_fd(fd)
+        };
+        let link = unsafe { bpf_raw_tracepoint_open(c"sched_switch".as_ptr(), prog.as_raw_fd()) };
+        assert!(link >= 0);
+        let link = unsafe { OwnedFd::from_raw_fd(link) };
+        let info = LinkInfo::from_fd(link.as_fd()).unwrap();
+        assert_eq!(info.type_, LinkType(BPF_LINK_TYPE_RAW_TRACEPOINT));
+        assert!(format!("{info:?}").contains("tp_name: \"sched_switch\""));
+        match info.details {
+            LinkDetails::RawTracepoint { tp_name, .. } => assert_eq!(tp_name, "sched_switch"),
+            _ => panic!("not a raw tracepoint link"),
+        }
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_loaded_objects() {
+        use std::os::fd::AsFd;
+
+        let Some(map) = create_map(BPF_MAP_TYPE_ARRAY, 1) else {
+            return;
+        };
+        let id = MapInfo::from_fd(map.as_fd()).unwrap().info.id;
+        let maps = loaded_maps()
+            .open_flags(BPF_F_RDONLY)
+            .collect::<std::io::Result<Vec<_>>>()
+            .unwrap();
+        assert!(maps.windows(2).all(|pair| pair[0].id < pair[1].id));
+        let found = maps.iter().find(|map| map.id == id).unwrap();
+        assert_eq!(found.info.info.id, id);
+        assert_eq!(found.info.info.type_, BPF_MAP_TYPE_ARRAY);
+
+        if std::path::Path::new("/sys/kernel/btf/vmlinux").exists() {
+            assert!(loaded_btfs().any(|btf| btf.is_ok_and(|btf| btf.info.name == "vmlinux")));
+        }
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_verifier_log() {
+        use libbpf_sys::insn::*;
+
+        // r0 is never set before exit.
+        let rejected = [BPF_MOV64_IMM(BPF_REG_1, 1), BPF_EXIT_INSN()];
+        let mut opts = bpf_prog_load_opts::new_sized();
+        let (fd, log) = prog_load_with_log(
+            BPF_PROG_TYPE_SOCKET_FILTER,
+            Some("rejected"),
+            "GPL",
+            &rejected,
+            &mut opts,
+            2,
+        );
+        assert!(fd.is_err());
+        assert!(opts.log_buf.is_null());
+        let parsed = VerifierLog::parse(&log);
+        assert_eq!(parsed.error(), Some("R0 !read_ok"), "{log}");
+        assert_eq!(parsed.last_insn(), Some(1));
+        assert!(parsed.lines.iter().any(|line| matches!(
+            line,
+            VerifierLogLine::Insn { idx: 0, code: 0xb7, text, state }
+                if text == "r1 = 1" && state.iter().any(|reg| reg.name == "R1" && reg.value == "1")
+        )));
+
+        let accepted = [BPF_MOV64_IMM(BPF_REG_0, 0), BPF_EXIT_INSN()];
+        let (fd, log) = prog_load_with_log(
+            BPF_PROG_TYPE_SOCKET_FILTER,
+            None,
+            "GPL",
+            &accepted,
+            &mut opts,
+            1,
+        );
+        fd.unwrap();
+        let parsed = VerifierLog::parse(&log);
+        let stats = parsed.stats().expect(&log);
+        assert_eq!(stats.processed_insns, 2);
+        assert!(stats.insn_limit > 0);
+
+        // `BPF_LOG_STATS` (4) prints the verification time and stack depth
+        // between the error and the `processed` line.
+        let (fd, log) = prog_load_with_log(
+            BPF_PROG_TYPE_SOCKET_FILTER,
+            None,
+            "GPL",
+            &rejected,
+            &mut opts,
+            1 | 4,
+        );
+        assert!(fd.is_err());
+        assert!(log.contains("stack depth"), "{log}");
+        let parsed = VerifierLog::parse(&log);
+        assert_eq!(parsed.error(), Some("R0 !read_ok"), "{log}");
+        assert_eq!(parsed.stats().unwrap().stack_depths, [0]);
+
+        let parsed = VerifierLog::parse(concat!(
+            "func#0 @0\n",
+            "0: R1=ctx() R10=fp0\n",
+            "; int x = 0; @ prog.bpf.c:7\n",
+            "0: (7a) *(u64 *)(r10 -8) = 0         ; R10=fp0 fp-8_w=00000000\n",
+            "1: (61) r2 = *(u32 *)(r1 +0)         ; R1=ctx() R2_w=scalar(smin=0,smax=umax=0xffffffff,var_off=(0x0; 0xffffffff))\n",
+            "2: (15) if r2 == 0x0 goto pc+1       ; R2_w=scalar()\n",
+            "from 2 to 4: frame1: R2_w=0 R10=fp0\n",
+            "4: (95) exit\n",
+            "R0 !read_ok\n",
+            "verification time 12 usec\n",
+            "stack depth 8+16\n",
+            "processed 4 insns (limit 1000000) max_states_per_insn 0 total_states 1 peak_states 1 mark_read 1\n",
+        ));
+        assert_eq!(parsed.error(), Some("R0 !read_ok"));
+        assert_eq!(
+            parsed.source_for(0),
+            Some(&VerifierLogLine::Source {
+                text: "int x = 0;".into(),
+                file: Some("prog.bpf.c".into()),
+                line: Some(7),
+            })
+        );
+        assert_eq!(
+            parsed.lines[4],
+            VerifierLogLine::Insn {
+                idx: 1,
+                code: 0x61,
+                text: "r2 = *(u32 *)(r1 +0)".into(),
+                state: vec![
+                    RegState {
+                        name: "R1".into(),
+                        written: false,
+                        value: "ctx()".into(),
+                    },
+                    RegState {
+                        name: "R2".into(),
+                        written: true,
+                        value: "scalar(smin=0,smax=umax=0xffffffff,var_off=(0x0; 0xffffffff))"
+                            .into(),
+                    },
+                ],
+            }
+        );
+        assert!(matches!(
+            &parsed.lines[6],
+            VerifierLogLine::Branch { from: 2, to: 4, state } if state.len() == 2
+        ));
+        assert_eq!(
+            parsed.stats(),
+            Some(&VerifierStats {
+                processed_insns: 4,
+                insn_limit: 1000000,
+                max_states_per_insn: 0,
+                total_states: 1,
+                peak_states: 1,
+                mark_read: 1,
+                verification_time_usec: 12,
+                stack_depths: vec![8, 16],
+            })
+        );
+    }
+
+    #[cfg(not(feature = "syscall-only"))]
+    #[test]
+    fn test_percpu_map() {
+        use std::os::fd::AsFd;
+
+        let cpus = num_possible_cpus().unwrap();
+        assert!(cpus > 0);
+        assert_eq!(possible_cpus().unwrap().len(), cpus);
+        let online = online_cpus().unwrap();
+        assert!(!online.is_empty() && online.len() <= cpus);
+        // 4-byte values are padded to 8 bytes per CPU.
+        assert_eq!(percpu_value_size(4).unwrap(), 8 * cpus);
+
+        let Some(array) = create_map(BPF_MAP_TYPE_ARRAY, 1) else {
+            return;
+        };
+        let err = PerCpuMap::from_fd(array.as_fd()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+        let Some(fd) = create_map(BPF_MAP_TYPE_PERCPU_HASH, 16) else {
+            return;
+        };
+        let map = PerCpuMap::from_fd(fd.as_fd()).unwrap();
+        assert_eq!((map.key_size(), map.value_size()), (4, 4));
+        assert!(map.lookup(&1u32.to_ne_bytes()).unwrap().is_none());
+        assert!(map.lookup(&[0; 8]).is_err());
+
+        let mut values = map.values();
+        assert_eq!(values.len(), cpus);
+        assert_eq!(values.as_bytes().len(), 8 * cpus);
+        for cpu in 0..cpus {
+            values
+                .get_mut(cpu)
+                .unwrap()
+                .copy_from_slice(&(cpu as u32 + 1).to_ne_bytes());
+        }
+        map.update(&1u32.to_ne_bytes(), &values, BPF_ANY as u64)
+            .unwrap();
+        assert_eq!(
+            map.lookup(&1u32.to_ne_bytes()).unwrap(),
+            Some(values.clone())
+        );
+        assert!(map
+            .update(
+                &2u32.to_ne_bytes(),
+                &PerCpuValues::with_cpus(4, cpus + 1),
+                0
+            )
+            .is_err());
+
+        let keys: Vec<[u8; 4]> = (2..10u32).map(u32::to_ne_bytes).collect();
+        let entries: Vec<_> = keys.iter().map(|key| (&key[..], &values)).collect();
+        map.update_batch(&entries, BPF_ANY as u64).unwrap();
+        let mut all = map.lookup_batch(1).unwrap();
+        all.sort_by_key(|(key, _)| u32::from_ne_bytes(key[..].try_into().unwrap()));
+        assert_eq!(all.len(), 9);
+        for (i, (key, found)) in all.iter().enumerate() {
+            assert_eq!(key[..], (i as u32 + 1).to_ne_bytes());
+            assert_eq!(found, &values);
+            let sum: u32 = found
+                .iter()
+                .map(|value| u32::from_ne_bytes(value.try_into().unwrap()))
+                .sum();
+            assert_eq!(sum as usize, cpus * (cpus + 1) / 2);
+        }
+    }
+
+    /// Checks that a `syscall-only` test binary did not load libbpf.
+    #[cfg(feature = "syscall-only")]
+    #[test]
+    fn test_syscall_only() {
+        let maps = std::fs::read_to_string("/proc/self/maps").unwrap();
+        assert!(!maps.contains("libbpf"), "{maps}");
+    }
+
+    /// Checks that a `fully-static` test binary has no program interpreter
+    /// and no `DT_NEEDED` entries in its dynamic section.
+    #[cfg(feature = "fully-static")]
+    #[test]
+    fn test_fully_static() {
+        const PT_DYNAMIC: u32 = 2;
+        const PT_INTERP: u32 = 3;
+        const DT_NULL: u64 = 0;
+        const DT_NEEDED: u64 = 1;
+
+        let exe = std::fs::read("/proc/self/exe").unwrap();
+        let u16_at = |off: usize| u16::from_ne_bytes(exe[off..off + 2].try_into().unwrap());
+        let u32_at = |off: usize| u32::from_ne_bytes(exe[off..off + 4].try_into().unwrap());
+        let u64_at = |off: usize| u64::from_ne_bytes(exe[off..off + 8].try_into().unwrap());
+
+        assert_eq!(&exe[..4], b"\x7fELF");
+        assert_eq!(exe[4], 2, "expected an ELF64 executable");
+
+        let phoff = u64_at(0x20) as usize;
+        let phentsize = u16_at(0x36) as usize;
+        let phnum = u16_at(0x38) as usize;
+
+        let mut needed = 0;
+        for i in 0..phnum {
+            let phdr = phoff + i * phentsize;
+            match u32_at(phdr) {
+                PT_INTERP => panic!("executable requests a program interpreter"),
+                PT_DYNAMIC => {
+                    let offset = u64_at(phdr + 0x08) as usize;
+                    let filesz = u64_at(phdr + 0x20) as usize;
+                    for dyn_ent in (offset..offset + filesz).step_by(16) {
+                        match u64_at(dyn_ent) {
+                            DT_NULL => break,
+                            DT_NEEDED => needed += 1,
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(needed, 0, "executable has DT_NEEDED entries");
+    }
 }